@@ -0,0 +1,82 @@
+//! Throughput of the reference and table-driven *Magma*
+//!
+//! Run with `cargo bench --bench magma`, add `--features constant-time`
+//! to measure the constant-time reference path.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use secure_radio::core::cipher::magma::fast::FastMagma;
+use secure_radio::core::cipher::magma::magma::MagmaBuilder;
+use secure_radio::core::cipher::modes::{ctr::Ctr, ecb::Ecb};
+use secure_radio::core::cipher::Cipher;
+
+const BLOCKS: usize = 1 << 20;
+const BUFFER_BLOCKS: usize = 4096;
+
+fn report(name: &str, seconds: f64) {
+    let mib = (BLOCKS * 8) as f64 / (1024.0 * 1024.0);
+    println!("{:<20} {:>10.2} MiB/s {:>10.1} ns/block", name, mib / seconds, seconds * 1e9 / BLOCKS as f64);
+}
+
+/// Chained blocks, every block depends on the previous one
+fn bench_single<C: Cipher<Input = u64, Output = u64>>(name: &str, cipher: &C) {
+    let start = Instant::now();
+    let mut block = 0u64;
+    for _ in 0..BLOCKS {
+        block = cipher.encrypt(black_box(block)).unwrap();
+    }
+    black_box(block);
+    report(name, start.elapsed().as_secs_f64());
+}
+
+/// Independent blocks handed over in one slice
+fn bench_blocks<C: Cipher<Input = u64, Output = u64>>(name: &str, cipher: &C) {
+    let mut blocks: Vec<u64> = (0..BUFFER_BLOCKS as u64).collect();
+    let start = Instant::now();
+    for _ in 0..BLOCKS / BUFFER_BLOCKS {
+        cipher.encrypt_blocks(black_box(&mut blocks)).unwrap();
+    }
+    black_box(&blocks);
+    report(name, start.elapsed().as_secs_f64());
+}
+
+fn bench_ecb<C: Cipher<Input = u64, Output = u64>>(name: &str, cipher: C) {
+    let ecb = Ecb::new(cipher);
+    let mut data = vec![0u8; BUFFER_BLOCKS * 8];
+    let start = Instant::now();
+    for _ in 0..BLOCKS / BUFFER_BLOCKS {
+        ecb.encrypt(black_box(&mut data)).unwrap();
+    }
+    black_box(&data);
+    report(name, start.elapsed().as_secs_f64());
+}
+
+fn bench_ctr<C: Cipher<Input = u64, Output = u64>>(name: &str, cipher: C) {
+    let ctr = Ctr::new(cipher);
+    let mut data = vec![0u8; BUFFER_BLOCKS * 8];
+    let start = Instant::now();
+    for iv in 0..(BLOCKS / BUFFER_BLOCKS) as u32 {
+        ctr.encrypt(iv, black_box(&mut data)).unwrap();
+    }
+    black_box(&data);
+    report(name, start.elapsed().as_secs_f64());
+}
+
+fn main() {
+    let key = [
+        0xffeeddcc, 0xbbaa9988, 0x77665544, 0x33221100,
+        0xf0f1f2f3, 0xf4f5f6f7, 0xf8f9fafb, 0xfcfdfeff,
+    ];
+    let reference = MagmaBuilder::new().set_key(key).build();
+    let fast = FastMagma::new(&reference);
+
+    bench_single("reference", &reference);
+    bench_single("fast", &fast);
+    bench_blocks("reference blocks", &reference);
+    bench_blocks("fast blocks", &fast);
+    bench_ecb("reference ecb", &reference);
+    bench_ecb("fast ecb", &fast);
+    bench_ctr("reference ctr", &reference);
+    bench_ctr("fast ctr", &fast);
+}
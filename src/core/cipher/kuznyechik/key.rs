@@ -0,0 +1,49 @@
+use crate::core::zeroize::Zeroize;
+
+// Master key
+pub enum Key {
+    U8([u8; 32]),
+    U128([u128; 2])
+}
+
+impl From<[u8; 32]> for Key {
+    fn from(value: [u8; 32]) -> Self {
+        Self::U8(value)
+    }
+}
+
+impl From<[u128; 2]> for Key {
+    fn from(value: [u128; 2]) -> Self {
+        Self::U128(value)
+    }
+}
+
+impl Key {
+    /// Returns key as big-endian bytes
+    pub fn to_bytes(&self) -> [u8; 32] {
+        match self {
+            Self::U8(bytes) => *bytes,
+            Self::U128([k_1, k_2]) => {
+                let mut bytes = [0u8; 32];
+                bytes[..16].copy_from_slice(&k_1.to_be_bytes());
+                bytes[16..].copy_from_slice(&k_2.to_be_bytes());
+                bytes
+            }
+        }
+    }
+}
+
+impl Zeroize for Key {
+    fn zeroize(&mut self) {
+        match self {
+            Self::U8(bytes) => bytes.zeroize(),
+            Self::U128(words) => words.zeroize(),
+        }
+    }
+}
+
+impl Drop for Key {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
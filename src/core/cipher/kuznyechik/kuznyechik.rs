@@ -0,0 +1,354 @@
+use crate::core::cipher::kuznyechik::consts::*;
+use crate::core::cipher::kuznyechik::key::Key;
+use crate::core::cipher::{kuznyechik::*, Cipher, CipherError, Rekey};
+use crate::core::zeroize::Zeroize;
+
+type Block = [u8; 16];
+
+pub struct KuznyechikBuilder {
+    pub (crate) key: [u8; 32]
+}
+
+impl KuznyechikBuilder {
+    pub fn new() -> Self {
+        Self { key: [0u8; 32] }
+    }
+
+    pub fn set_key(&mut self, key: impl Into<Key>) -> &mut Self {
+        self.key = key.into().to_bytes();
+        self
+    }
+
+    pub fn build(&mut self) -> Kuznyechik {
+        self.into()
+    }
+
+    /// Overwrites the stored key with zeros
+    pub fn wipe(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Zeroize for KuznyechikBuilder {
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+impl Drop for KuznyechikBuilder {
+    fn drop(&mut self) {
+        self.wipe();
+    }
+}
+
+impl Default for KuznyechikBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&mut KuznyechikBuilder> for Kuznyechik {
+    fn from(value: &mut KuznyechikBuilder) -> Self {
+        Kuznyechik::new(value.key)
+    }
+}
+
+
+#[derive(Clone)]
+pub struct Kuznyechik {
+    pub (crate) round_keys: [Block; 10]
+}
+
+impl Kuznyechik {
+    // Constructs cipher
+    pub fn new(mut key: [u8; 32]) -> Self {
+        let mut me = Self {
+            round_keys: [[0u8; 16]; 10]
+        };
+
+        me.prepare_round_keys(&key);
+        key.zeroize();
+        me
+    }
+
+    /// Replaces the key and recomputes round keys
+    pub fn set_key(&mut self, key: impl Into<Key>) {
+        let mut bytes = key.into().to_bytes();
+        self.prepare_round_keys(&bytes);
+        bytes.zeroize();
+    }
+
+    /// Overwrites round keys with zeros.
+    /// The cipher must be given a new key before further use
+    pub fn wipe(&mut self) {
+        self.zeroize();
+    }
+
+    /// [Key schedule](https://datatracker.ietf.org/doc/html/rfc7801.html#section-4.3)
+    fn prepare_round_keys(&mut self, key: &[u8; 32]) {
+        let mut k_1: Block = [0u8; 16];
+        let mut k_2: Block = [0u8; 16];
+        k_1.copy_from_slice(&key[..16]);
+        k_2.copy_from_slice(&key[16..]);
+
+        self.round_keys[0] = k_1;
+        self.round_keys[1] = k_2;
+
+        for pair in 1..5 {
+            for i in 1..=8 {
+                // C_i = L(Vec_128(i))
+                let mut constant = [0u8; 16];
+                constant[15] = (8 * (pair - 1) + i) as u8;
+                Self::transformation_l(&mut constant);
+
+                // F[C_i](a_1, a_0) = (LSX[C_i](a_1) xor a_0, a_1)
+                let mut t = k_1;
+                Self::transformation_x(&mut t, &constant);
+                Self::transformation_s(&mut t);
+                Self::transformation_l(&mut t);
+                Self::transformation_x(&mut t, &k_2);
+                k_2 = k_1;
+                k_1 = t;
+            }
+            self.round_keys[2 * pair] = k_1;
+            self.round_keys[2 * pair + 1] = k_2;
+        }
+        k_1.zeroize();
+        k_2.zeroize();
+    }
+
+    /// [Transformation](https://datatracker.ietf.org/doc/html/rfc7801.html#section-2)
+    ///
+    /// `X[k]: V_128 -> V_128`
+    #[inline]
+    fn transformation_x(a: &mut Block, k: &Block) {
+        a.iter_mut().zip(k.iter()).for_each(|(a, k)| *a ^= k);
+    }
+
+    /// [Transformation](https://datatracker.ietf.org/doc/html/rfc7801.html#section-2)
+    ///
+    /// `S: V_128 -> V_128`
+    #[inline]
+    fn transformation_s(a: &mut Block) {
+        a.iter_mut().for_each(|a| *a = PI[*a as usize]);
+    }
+
+    /// [Transformation](https://datatracker.ietf.org/doc/html/rfc7801.html#section-2)
+    ///
+    /// `S^(-1): V_128 -> V_128`
+    #[inline]
+    fn transformation_s_inv(a: &mut Block) {
+        a.iter_mut().for_each(|a| *a = PI_INV[*a as usize]);
+    }
+
+    /// [Transformation](https://datatracker.ietf.org/doc/html/rfc7801.html#section-2)
+    ///
+    /// `l: V_8^16 -> V_8`
+    #[inline]
+    fn transformation_small_l(a: &Block) -> u8 {
+        a.iter()
+            .zip(LINEAR.iter())
+            .fold(0, |acc, (&a, &c)| acc ^ utils::gf_mul(a, c))
+    }
+
+    /// [Transformation](https://datatracker.ietf.org/doc/html/rfc7801.html#section-2)
+    ///
+    /// `R: V_128 -> V_128`
+    #[inline]
+    fn transformation_r(a: &mut Block) {
+        let l = Self::transformation_small_l(a);
+        a.copy_within(0..15, 1);
+        a[0] = l;
+    }
+
+    /// [Transformation](https://datatracker.ietf.org/doc/html/rfc7801.html#section-2)
+    ///
+    /// `R^(-1): V_128 -> V_128`
+    #[inline]
+    fn transformation_r_inv(a: &mut Block) {
+        let a_15 = a[0];
+        a.copy_within(1..16, 0);
+        a[15] = a_15;
+        a[15] = Self::transformation_small_l(a);
+    }
+
+    /// [Transformation](https://datatracker.ietf.org/doc/html/rfc7801.html#section-2)
+    ///
+    /// `L: V_128 -> V_128`
+    #[inline]
+    fn transformation_l(a: &mut Block) {
+        (0..16).for_each(|_| Self::transformation_r(a));
+    }
+
+    /// [Transformation](https://datatracker.ietf.org/doc/html/rfc7801.html#section-2)
+    ///
+    /// `L^(-1): V_128 -> V_128`
+    #[inline]
+    fn transformation_l_inv(a: &mut Block) {
+        (0..16).for_each(|_| Self::transformation_r_inv(a));
+    }
+}
+
+impl Zeroize for Kuznyechik {
+    fn zeroize(&mut self) {
+        self.round_keys.zeroize();
+    }
+}
+
+impl Drop for Kuznyechik {
+    fn drop(&mut self) {
+        self.wipe();
+    }
+}
+
+impl Rekey for Kuznyechik {
+    fn rekey(&mut self, key: [u8; 32]) {
+        self.set_key(key);
+    }
+}
+
+impl Cipher for Kuznyechik {
+    type Input = u128;
+    type Output = u128;
+    /// Returns [encrypted block](https://datatracker.ietf.org/doc/html/rfc7801.html#section-4.1) as `u128` value
+    ///
+    /// # Arguments
+    ///
+    /// * `block_in` - a plaintext value as `u128`
+    fn encrypt(&self, block_in: Self::Input) -> Result<Self::Output, CipherError> {
+        let mut a = block_in.to_be_bytes();
+
+        for round_key in &self.round_keys[..9] {
+            Self::transformation_x(&mut a, round_key);
+            Self::transformation_s(&mut a);
+            Self::transformation_l(&mut a);
+        }
+        Self::transformation_x(&mut a, &self.round_keys[9]);
+
+        Ok(u128::from_be_bytes(a))
+    }
+
+    /// Returns [decrypted block](https://datatracker.ietf.org/doc/html/rfc7801.html#section-4.2) as `u128` value
+    ///
+    /// # Arguments
+    ///
+    /// * `block_in` - a ciphertext value as `u128`
+    fn decrypt(&self, block_in: Self::Output) -> Result<Self::Input, CipherError> {
+        let mut a = block_in.to_be_bytes();
+
+        Self::transformation_x(&mut a, &self.round_keys[9]);
+        for round_key in self.round_keys[..9].iter().rev() {
+            Self::transformation_l_inv(&mut a);
+            Self::transformation_s_inv(&mut a);
+            Self::transformation_x(&mut a, round_key);
+        }
+
+        Ok(u128::from_be_bytes(a))
+    }
+}
+
+
+#[cfg(test)]
+mod kuznyechik_test {
+    use super::*;
+    use crate::test_purpose::*;
+
+    #[test]
+    fn transformation_s_rfc7801() {
+        for (input, output) in KUZNYECHIK_TRANSFORMATION_S {
+            let mut a = input.to_be_bytes();
+            Kuznyechik::transformation_s(&mut a);
+            assert_eq!(u128::from_be_bytes(a), output);
+            Kuznyechik::transformation_s_inv(&mut a);
+            assert_eq!(u128::from_be_bytes(a), input);
+        }
+    }
+
+    #[test]
+    fn transformation_r_rfc7801() {
+        for (input, output) in KUZNYECHIK_TRANSFORMATION_R {
+            let mut a = input.to_be_bytes();
+            Kuznyechik::transformation_r(&mut a);
+            assert_eq!(u128::from_be_bytes(a), output);
+            Kuznyechik::transformation_r_inv(&mut a);
+            assert_eq!(u128::from_be_bytes(a), input);
+        }
+    }
+
+    #[test]
+    fn transformation_l_rfc7801() {
+        for (input, output) in KUZNYECHIK_TRANSFORMATION_L {
+            let mut a = input.to_be_bytes();
+            Kuznyechik::transformation_l(&mut a);
+            assert_eq!(u128::from_be_bytes(a), output);
+            Kuznyechik::transformation_l_inv(&mut a);
+            assert_eq!(u128::from_be_bytes(a), input);
+        }
+    }
+
+    #[test]
+    fn key_schedule_rfc7801() {
+        let kuznyechik = KuznyechikBuilder::new()
+            .set_key(KUZNYECHIK_KEY)
+            .build();
+
+        for (round_key, expected) in kuznyechik.round_keys.iter().zip(KUZNYECHIK_ROUND_KEYS) {
+            assert_eq!(u128::from_be_bytes(*round_key), expected);
+        }
+    }
+
+    #[test]
+    fn encrypt_rfc7801() {
+        let kuznyechik = KuznyechikBuilder::new()
+            .set_key(KUZNYECHIK_KEY)
+            .build();
+
+        assert_eq!(kuznyechik.encrypt(KUZNYECHIK_PLAINTEXT).unwrap(), KUZNYECHIK_CIPHERTEXT);
+    }
+
+    #[test]
+    fn decrypt_rfc7801() {
+        let kuznyechik = KuznyechikBuilder::new()
+            .set_key(KUZNYECHIK_KEY)
+            .build();
+
+        assert_eq!(kuznyechik.decrypt(KUZNYECHIK_CIPHERTEXT).unwrap(), KUZNYECHIK_PLAINTEXT);
+    }
+
+    #[test]
+    fn key_forms_are_equivalent() {
+        let from_bytes = KuznyechikBuilder::new().set_key(KUZNYECHIK_KEY).build();
+        let from_words = KuznyechikBuilder::new()
+            .set_key([KUZNYECHIK_ROUND_KEYS[0], KUZNYECHIK_ROUND_KEYS[1]])
+            .build();
+
+        assert_eq!(from_bytes.round_keys, from_words.round_keys);
+    }
+
+    #[test]
+    fn wipe_clears_key_material() {
+        let mut builder = KuznyechikBuilder::new();
+        builder.set_key(KUZNYECHIK_KEY);
+        let mut kuznyechik = builder.build();
+
+        builder.wipe();
+        kuznyechik.wipe();
+
+        assert_eq!(builder.key, [0u8; 32]);
+        assert_eq!(kuznyechik.round_keys, [[0u8; 16]; 10]);
+    }
+
+    #[test]
+    fn drop_clears_key_material() {
+        use core::mem::MaybeUninit;
+        use core::ptr::addr_of;
+
+        let mut slot = MaybeUninit::new(KuznyechikBuilder::new().set_key(KUZNYECHIK_KEY).build());
+        // SAFETY: the slot is initialized; after the drop only plain integer
+        // fields are read, their storage is still owned by the slot
+        unsafe {
+            slot.assume_init_drop();
+            assert_eq!(addr_of!((*slot.as_ptr()).round_keys).read(), [[0u8; 16]; 10]);
+        }
+    }
+}
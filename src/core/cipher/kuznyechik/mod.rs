@@ -0,0 +1,5 @@
+pub mod consts;
+pub mod utils;
+#[allow(clippy::module_inception)]
+pub mod kuznyechik;
+pub mod key;
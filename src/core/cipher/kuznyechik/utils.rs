@@ -0,0 +1,43 @@
+//! Utility functions
+
+/// Returns product in `GF(2^8)` defined by `x^8 + x^7 + x^6 + x + 1`
+///
+/// # Arguments
+///
+/// * a - `u8` value to multiply
+/// * b - `u8` value to multiply
+#[inline]
+pub const fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut res = 0;
+    let mut i = 0;
+    // Fixed number of iterations without branches on operands
+    while i < 8 {
+        res ^= a & (b & 1).wrapping_neg();
+        // 0xC3 = x^7 + x^6 + x + 1 (x^8 is dropped by the shift)
+        a = (a << 1) ^ (0xC3 & (a >> 7).wrapping_neg());
+        b >>= 1;
+        i += 1;
+    }
+    res
+}
+
+/// Returns splitted into `(u64, u64)` result
+///
+/// # Argument
+///
+/// * v - as `u128` value
+#[inline]
+pub fn u128_split(v: u128) -> (u64, u64) {
+    ((v >> 64) as u64, v as u64)
+}
+
+/// Returns joined `u128` result
+///
+/// # Argument
+///
+/// * a - `u64` value to join
+/// * b - `u64` value to join
+#[inline]
+pub fn u64_join(a: u64, b: u64) -> u128 {
+    ((a as u128) << 64) | (b as u128)
+}
@@ -0,0 +1,107 @@
+//! Interleaved processing of independent blocks
+//!
+//! Rounds of up to eight blocks are computed side by side, so that the CPU
+//! can overlap their dependency chains instead of waiting on every lookup.
+
+use crate::core::cipher::magma::param_set::ByteOrder;
+use crate::core::cipher::magma::utils;
+
+/// Encrypts, or decrypts if `DECRYPT` is set, `blocks` in place
+///
+/// # Arguments
+///
+/// * `round_keys` - keys in encryption order
+/// * `byte_order` - mapping of blocks to RFC 8891 order
+/// * `g` - round function `g[k](a)`
+/// * `blocks` - blocks of any count, processed in groups of 8, 4, 2 and 1
+#[inline(always)]
+pub(crate) fn process_blocks<const DECRYPT: bool>(
+    round_keys: &[u32; 32],
+    byte_order: ByteOrder,
+    g: impl Fn(u32, u32) -> u32,
+    blocks: &mut [u64],
+) {
+    let mut chunks = blocks.chunks_exact_mut(8);
+    for chunk in &mut chunks {
+        rounds::<8, DECRYPT>(round_keys, byte_order, &g, chunk);
+    }
+
+    let mut rest = chunks.into_remainder();
+    if rest.len() >= 4 {
+        let (head, tail) = rest.split_at_mut(4);
+        rounds::<4, DECRYPT>(round_keys, byte_order, &g, head);
+        rest = tail;
+    }
+    if rest.len() >= 2 {
+        let (head, tail) = rest.split_at_mut(2);
+        rounds::<2, DECRYPT>(round_keys, byte_order, &g, head);
+        rest = tail;
+    }
+    if !rest.is_empty() {
+        rounds::<1, DECRYPT>(round_keys, byte_order, &g, rest);
+    }
+}
+
+/// Runs all 32 rounds over exactly `N` blocks
+#[inline(always)]
+fn rounds<const N: usize, const DECRYPT: bool>(
+    round_keys: &[u32; 32],
+    byte_order: ByteOrder,
+    g: &impl Fn(u32, u32) -> u32,
+    blocks: &mut [u64],
+) {
+    let mut a_1 = [0u32; N];
+    let mut a_0 = [0u32; N];
+    for ((x_1, x_0), &block) in a_1.iter_mut().zip(a_0.iter_mut()).zip(blocks.iter()) {
+        (*x_1, *x_0) = utils::u64_split(byte_order.reorder(block));
+    }
+
+    for round in 0..32 {
+        let k = round_keys[if DECRYPT { 31 - round } else { round }];
+        for (x_1, x_0) in a_1.iter_mut().zip(a_0.iter_mut()) {
+            (*x_1, *x_0) = (*x_0, g(k, *x_0) ^ *x_1);
+        }
+    }
+
+    for ((&x_1, &x_0), block) in a_1.iter().zip(a_0.iter()).zip(blocks.iter_mut()) {
+        *block = byte_order.reorder(utils::u32_join(x_0, x_1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::cipher::magma::fast::FastMagma;
+    use crate::core::cipher::magma::magma::MagmaBuilder;
+    use crate::core::cipher::Cipher;
+    use crate::test_purpose::*;
+
+    #[test]
+    fn every_group_size_matches_single_blocks() {
+        let mut rng = XorShift64(0x5DEE_CE66_D1CE_4E5B);
+        let magma = MagmaBuilder::new().set_key(CIPHER_KEY).build();
+        let fast = FastMagma::new(&magma);
+
+        for len in 0..=19 {
+            let mut plaintext = [0u64; 19];
+            plaintext.iter_mut().for_each(|b| *b = rng.next_u64());
+            let plaintext = &plaintext[..len];
+
+            let mut expected = [0u64; 19];
+            for (e, &p) in expected.iter_mut().zip(plaintext) {
+                *e = magma.encrypt(p).unwrap();
+            }
+
+            let mut blocks = [0u64; 19];
+            blocks[..len].copy_from_slice(plaintext);
+            magma.encrypt_blocks(&mut blocks[..len]).unwrap();
+            assert_eq!(blocks[..len], expected[..len]);
+            magma.decrypt_blocks(&mut blocks[..len]).unwrap();
+            assert_eq!(&blocks[..len], plaintext);
+
+            fast.encrypt_blocks(&mut blocks[..len]).unwrap();
+            assert_eq!(blocks[..len], expected[..len]);
+            fast.decrypt_blocks(&mut blocks[..len]).unwrap();
+            assert_eq!(&blocks[..len], plaintext);
+        }
+    }
+}
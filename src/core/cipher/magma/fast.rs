@@ -0,0 +1,178 @@
+//! Table-driven *Magma*
+//!
+//! `t` followed by the rotation `<<< 11` is linear over the four bytes of its
+//! input, so `g[k](a)` is computed as a XOR of four lookups into 256-entry
+//! tables prepared once from the s-box. Lookups are indexed with secret bytes,
+//! use [`Magma`] with the `constant-time` feature where timing matters.
+
+use crate::core::cipher::magma::magma::Magma;
+use crate::core::cipher::magma::param_set::ByteOrder;
+use crate::core::cipher::{magma::*, Cipher, CipherError};
+use crate::core::zeroize::Zeroize;
+
+/// `t` and `<<< 11` combined for every byte of the input word
+pub(crate) type Tables = [[u32; 256]; 4];
+
+pub struct FastMagma {
+    pub (crate) round_keys: [u32; 32],
+    pub (crate) tables: Tables,
+    pub (crate) byte_order: ByteOrder
+}
+
+impl FastMagma {
+    /// Constructs cipher with the key, s-box and byte order of `magma`
+    pub fn new(magma: &Magma) -> Self {
+        Self {
+            round_keys: magma.round_keys,
+            tables: Self::prepare_tables(&magma.sbox),
+            byte_order: magma.byte_order
+        }
+    }
+
+    /// Overwrites round keys with zeros.
+    /// The cipher must not be used afterwards
+    pub fn wipe(&mut self) {
+        self.round_keys.zeroize();
+    }
+
+    fn prepare_tables(sbox: &[u8; 128]) -> Tables {
+        let mut tables = [[0u32; 256]; 4];
+        for (byte, table) in tables.iter_mut().enumerate() {
+            for (x, entry) in table.iter_mut().enumerate() {
+                let low = sbox[2 * byte * 16 + (x & 0xF)] as u32;
+                let high = sbox[(2 * byte + 1) * 16 + (x >> 4)] as u32;
+                *entry = (((high << 4) | low) << (8 * byte)).rotate_left(11);
+            }
+        }
+        tables
+    }
+
+    /// [Transformation](https://datatracker.ietf.org/doc/html/rfc8891.html#section-4.2)
+    ///
+    /// `g[k]: V_32 -> V_32`
+    #[inline]
+    pub (crate) fn transformation_g(&self, k: u32, a: u32) -> u32 {
+        let x = k.wrapping_add(a);
+        self.tables[0][(x & 0xFF) as usize]
+            ^ self.tables[1][(x >> 8 & 0xFF) as usize]
+            ^ self.tables[2][(x >> 16 & 0xFF) as usize]
+            ^ self.tables[3][(x >> 24) as usize]
+    }
+}
+
+impl From<&Magma> for FastMagma {
+    fn from(value: &Magma) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Drop for FastMagma {
+    fn drop(&mut self) {
+        self.wipe();
+    }
+}
+
+impl Cipher for FastMagma {
+    type Input = u64;
+    type Output = u64;
+
+    /// Returns [encrypted block](https://datatracker.ietf.org/doc/html/rfc8891.html#section-5.1) as `u64` value
+    ///
+    /// # Arguments
+    ///
+    /// * `block_in` - a plaintext value as `u64`
+    #[inline]
+    fn encrypt(&self, block_in: Self::Input) -> Result<Self::Output, CipherError> {
+        let (mut a_1, mut a_0) = utils::u64_split(self.byte_order.reorder(block_in));
+
+        for &k in self.round_keys.iter() {
+            (a_1, a_0) = (a_0, self.transformation_g(k, a_0) ^ a_1);
+        }
+
+        Ok(self.byte_order.reorder(utils::u32_join(a_0, a_1)))
+    }
+
+    /// Returns [decrypted block](https://datatracker.ietf.org/doc/html/rfc8891.html#section-5.2) as `u64` value
+    ///
+    /// # Arguments
+    ///
+    /// * `block_in` - a ciphertext value as `u64`
+    #[inline]
+    fn decrypt(&self, block_in: Self::Output) -> Result<Self::Input, CipherError> {
+        let (mut b_1, mut b_0) = utils::u64_split(self.byte_order.reorder(block_in));
+
+        for &k in self.round_keys.iter().rev() {
+            (b_1, b_0) = (b_0, self.transformation_g(k, b_0) ^ b_1);
+        }
+
+        Ok(self.byte_order.reorder(utils::u32_join(b_0, b_1)))
+    }
+
+    /// Encrypts `blocks` in place, interleaving up to eight blocks per round
+    fn encrypt_blocks(&self, blocks: &mut [u64]) -> Result<(), CipherError> {
+        batch::process_blocks::<false>(&self.round_keys, self.byte_order, |k, a| self.transformation_g(k, a), blocks);
+        Ok(())
+    }
+
+    /// Decrypts `blocks` in place, interleaving up to eight blocks per round
+    fn decrypt_blocks(&self, blocks: &mut [u64]) -> Result<(), CipherError> {
+        batch::process_blocks::<true>(&self.round_keys, self.byte_order, |k, a| self.transformation_g(k, a), blocks);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cipher::magma::magma::MagmaBuilder;
+    use crate::core::cipher::magma::param_set::SboxParamSet;
+    use crate::test_purpose::*;
+
+    #[test]
+    fn transformation_g_rfc8891() {
+        let magma = FastMagma::new(&MagmaBuilder::default().build());
+
+        for ((k, a), output) in TRANSFORMATION_G {
+            assert_eq!(magma.transformation_g(k, a), output);
+        }
+    }
+
+    #[test]
+    fn encrypt_decrypt_rfc8891() {
+        let magma = FastMagma::new(&MagmaBuilder::new().set_key(CIPHER_KEY).build());
+
+        assert_eq!(magma.encrypt(PLAINTEXT).unwrap(), CIPHERTEXT);
+        assert_eq!(magma.decrypt(CIPHERTEXT).unwrap(), PLAINTEXT);
+    }
+
+    #[test]
+    fn matches_reference() {
+        let mut rng = XorShift64(0x0123_4567_89AB_CDEF);
+
+        for set in [SboxParamSet::Tc26Z, SboxParamSet::Test, SboxParamSet::CryptoProD] {
+            for byte_order in [ByteOrder::Rfc8891, ByteOrder::Gost28147] {
+                let reference = MagmaBuilder::new()
+                    .set_key([rng.next_u64() as u32; 8])
+                    .set_param_set(set)
+                    .set_byte_order(byte_order)
+                    .build();
+                let fast = FastMagma::from(&reference);
+
+                for _ in 0..256 {
+                    let block = rng.next_u64();
+                    let ciphertext = reference.encrypt(block).unwrap();
+                    assert_eq!(fast.encrypt(block).unwrap(), ciphertext);
+                    assert_eq!(fast.decrypt(ciphertext).unwrap(), block);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn wipe_clears_round_keys() {
+        let mut magma = FastMagma::new(&MagmaBuilder::new().set_key(CIPHER_KEY).build());
+
+        magma.wipe();
+        assert_eq!(magma.round_keys, [0u32; 32]);
+    }
+}
@@ -1,4 +1,12 @@
-// Round keys
+use crate::core::cipher::magma::param_set::ByteOrder;
+use crate::core::zeroize::Zeroize;
+
+#[derive(Debug)]
+pub enum KeyError {
+    InvalidLength,
+}
+
+// Master key
 pub enum Key {
     U8([u8;32]),
     U32([u32; 8])
@@ -15,3 +23,73 @@ impl From<[u32; 8]> for Key {
         Self::U32(value)
     }
 }
+
+/// Returns key made of exactly 32 bytes
+impl TryFrom<&[u8]> for Key {
+    type Error = KeyError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        value.try_into()
+            .map(Self::U8)
+            .map_err(|_| KeyError::InvalidLength)
+    }
+}
+
+impl Key {
+    /// Returns key bytes in [RFC 8891](https://datatracker.ietf.org/doc/html/rfc8891.html#section-4.3) order
+    pub fn to_bytes(&self) -> [u8; 32] {
+        match self {
+            Key::U8(bytes) => *bytes,
+            Key::U32(words) => {
+                let mut bytes = [0u8; 32];
+                for (chunk, word) in bytes.chunks_exact_mut(4).zip(words) {
+                    chunk.copy_from_slice(&word.to_be_bytes());
+                }
+                bytes
+            }
+        }
+    }
+
+    /// Returns key words, bytes are read according to `order`
+    pub fn to_words(&self, order: ByteOrder) -> [u32; 8] {
+        match self {
+            Key::U32(words) => *words,
+            Key::U8(bytes) => {
+                let mut words = [0u32; 8];
+                for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+                    let chunk = [chunk[0], chunk[1], chunk[2], chunk[3]];
+                    *word = match order {
+                        ByteOrder::Rfc8891 => u32::from_be_bytes(chunk),
+                        ByteOrder::Gost28147 => u32::from_le_bytes(chunk),
+                    };
+                }
+                words
+            }
+        }
+    }
+}
+
+impl Zeroize for Key {
+    fn zeroize(&mut self) {
+        match self {
+            Key::U8(bytes) => bytes.zeroize(),
+            Key::U32(words) => words.zeroize(),
+        }
+    }
+}
+
+/// Temporary keys, replaced keys of builders and keys unwrapped by
+/// `Kexp15::import` are wiped when they go out of scope
+impl Drop for Key {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// Returns key words, bytes are read in [RFC 8891](https://datatracker.ietf.org/doc/html/rfc8891.html#section-4.3) order:
+/// `K_1` is made of the first four bytes
+impl From<Key> for [u32; 8] {
+    fn from(value: Key) -> Self {
+        value.to_words(ByteOrder::Rfc8891)
+    }
+}
@@ -1,75 +1,118 @@
 use crate::core::cipher::magma::consts::*;
-use crate::core::cipher::{magma::*, Cipher, CipherError};
+use crate::core::cipher::magma::key::Key;
+use crate::core::cipher::magma::param_set::{ByteOrder, SboxParamSet};
+use crate::core::cipher::{magma::*, Cipher, CipherError, Rekey};
+use crate::core::zeroize::Zeroize;
+
+#[derive(Debug)]
+pub enum MagmaBuilderError {
+    MissingKey,
+}
 
 pub struct MagmaBuilder {
-    pub (crate) key: Option<[u32; 8]>,
-    pub (crate) round_keys: Option<[u32; 32]>,
-    pub (crate) sbox: Option<[u8; 128]>
+    pub (crate) key: Option<Key>,
+    pub (crate) sbox: Option<[u8; 128]>,
+    pub (crate) byte_order: ByteOrder
 }
 
 impl MagmaBuilder {
+    /// Constructs builder without key.
+    /// If no s-box is set, [RFC 8891](https://datatracker.ietf.org/doc/html/rfc8891.html#section-4.1) one is used.
     pub fn new() -> Self {
-        Self { key: None, round_keys: None, sbox: None}
+        Self { key: None, sbox: None, byte_order: ByteOrder::Rfc8891 }
     }
 
-    pub fn set_key(&mut self, key: [u32; 8]) -> &mut Self {
-        self.key = Some(key);
+    /// Sets key given as `[u32; 8]` or as `[u8; 32]`,
+    /// bytes are read in the order set by `set_byte_order`
+    pub fn set_key(&mut self, key: impl Into<Key>) -> &mut Self {
+        self.key = Some(key.into());
         self
     }
 
-    pub fn set_round_keys(&mut self, round_keys: [u32; 32]) -> &mut Self {
-        self.round_keys = Some(round_keys);
+    pub fn set_sbox(&mut self, sbox: [u8; 128]) -> &mut Self {
+        self.sbox = Some(sbox);
         self
     }
 
-    pub fn set_sbox(&mut self, sbox: [u8; 128]) -> &mut Self {
-        self.sbox = Some(sbox);
+    /// Sets one of the standard s-boxes
+    pub fn set_param_set(&mut self, param_set: SboxParamSet) -> &mut Self {
+        self.set_sbox(param_set.sbox())
+    }
+
+    /// Sets byte order of keys and blocks, RFC 8891 by default
+    pub fn set_byte_order(&mut self, byte_order: ByteOrder) -> &mut Self {
+        self.byte_order = byte_order;
         self
     }
 
+    /// Returns cipher or the name of the missing field
+    pub fn try_build(&mut self) -> Result<Magma, MagmaBuilderError> {
+        self.try_into()
+    }
+
+    /// Returns cipher
+    ///
+    /// # Panics
+    ///
+    /// If the key is not set, use `try_build` to handle it
     pub fn build(&mut self) -> Magma {
-        self.into()
+        self.try_build().expect("MagmaBuilder: key is not set")
+    }
+
+    /// Overwrites the stored key with zeros and unsets it
+    pub fn wipe(&mut self) {
+        if let Some(key) = self.key.as_mut() {
+            key.zeroize();
+        }
+        self.key = None;
+    }
+}
+
+impl Drop for MagmaBuilder {
+    fn drop(&mut self) {
+        self.wipe();
     }
 }
 
 impl Default for MagmaBuilder {
     fn default() -> Self {
         Self {
-            key: Some([0u32; 8]),
-            round_keys: Some([0u32; 32]),
-            sbox: Some(SBOX.clone())
+            key: Some(Key::U32([0u32; 8])),
+            sbox: Some(SBOX),
+            byte_order: ByteOrder::Rfc8891
         }
     }
 }
 
-impl From<&mut MagmaBuilder> for Magma {
-    fn from(value: &mut MagmaBuilder) -> Self {
-        if let &mut MagmaBuilder { 
-            key: Some(k),
-            round_keys: Some(rk),
-            sbox: Some(sbox)
-        } = value {
-            Magma::new(k, rk, sbox)
-        } else {
-            loop {}
-        }
+impl TryFrom<&mut MagmaBuilder> for Magma {
+    type Error = MagmaBuilderError;
+
+    fn try_from(value: &mut MagmaBuilder) -> Result<Self, Self::Error> {
+        let key = value.key.as_ref().ok_or(MagmaBuilderError::MissingKey)?;
+        let sbox = value.sbox.unwrap_or(SBOX);
+        let mut magma = Magma::new(key.to_words(value.byte_order), sbox);
+        magma.byte_order = value.byte_order;
+        Ok(magma)
     }
 }
 
 
+#[derive(Clone)]
 pub struct Magma {
     pub (crate) key: [u32; 8],
     pub (crate) round_keys: [u32; 32],
-    pub (crate) sbox: [u8; 128]
+    pub (crate) sbox: [u8; 128],
+    pub (crate) byte_order: ByteOrder
 }
 
 impl Magma {
-    // Constructs cipher
-    pub fn new(key: [u32; 8], round_keys: [u32; 32], sbox: [u8; 128]) -> Self {
+    // Constructs cipher, round keys are derived from `key`
+    pub fn new(key: [u32; 8], sbox: [u8; 128]) -> Self {
         let mut me = Self {
             key,
-            round_keys,
-            sbox
+            round_keys: [0u32; 32],
+            sbox,
+            byte_order: ByteOrder::Rfc8891
         };
 
         me.prepare_round_keys();
@@ -79,13 +122,17 @@ impl Magma {
 
     
 
-    pub(crate) fn set_key_u32(&mut self, key: &[u32; 8]) {
-        self.key.clone_from(key);
+    /// Replaces the key and recomputes round keys
+    pub fn set_key(&mut self, key: impl Into<Key>) {
+        self.key = key.into().to_words(self.byte_order);
         self.prepare_round_keys();
     }
 
-    pub(crate) fn set_key_u8(&mut self, bytes: &[u8]) {
-        self.set_key_u32(&Self::key_from_u8(bytes));
+    /// Overwrites the key and round keys with zeros, the s-box is public and kept.
+    /// The cipher must be given a new key before further use
+    pub fn wipe(&mut self) {
+        self.key.zeroize();
+        self.round_keys.zeroize();
     }
 
     pub (crate) fn set_substitution_box(&mut self, substitution_box: &[u8; 128]) {
@@ -98,29 +145,26 @@ impl Magma {
             2, 1, 0,
         ];
 
-        for index in 0..32 {
-            let round_key_position = ROUND_KEY_POSITION[index] as usize;
-            self.round_keys[index] = self.key[round_key_position];
-        }
-    }
-
-    fn key_from_u8(bytes: &[u8]) -> [u32;8] {
-        assert!(bytes.len() == 32);
-        let mut key = [0_u32;8];
-        let mut array_u8 = [0u8; 4];
-        for (index, chunk) in bytes.chunks(4).enumerate() {
-            chunk.iter().enumerate().for_each(|t| array_u8[t.0] = *t.1);
-            key[index] = u32::from_be_bytes(array_u8);
+        for (round_key, &position) in self.round_keys.iter_mut().zip(ROUND_KEY_POSITION.iter()) {
+            *round_key = self.key[position as usize];
         }
-        key 
     }
 
 
     /// [Transformation](https://datatracker.ietf.org/doc/html/rfc8891.html#section-4.2)
     ///
-    /// `t: V_32 -> V_32`
+    /// `t: V_32 -> V_32`, constant-time when the `constant-time` feature is enabled
     #[inline]
     fn transformation_t(&self, a: u32) -> u32 {
+        #[cfg(feature = "constant-time")]
+        return self.transformation_t_constant_time(a);
+        #[cfg(not(feature = "constant-time"))]
+        return self.transformation_t_reference(a);
+    }
+
+    /// `t` with s-box entries looked up by secret-dependent indices
+    #[inline]
+    fn transformation_t_reference(&self, a: u32) -> u32 {
         let mut res: u32 = 0;
         let mut shift_count = 0;
         for i in 0..8 {
@@ -132,6 +176,24 @@ impl Magma {
         res
     }
 
+    /// `t` reading every entry of every s-box row, so that neither the
+    /// memory access pattern nor the timing depends on `a`
+    #[inline]
+    fn transformation_t_constant_time(&self, a: u32) -> u32 {
+        let mut res: u32 = 0;
+        for (i, row) in self.sbox.chunks_exact(16).enumerate() {
+            let v = (a >> (4 * i)) & 0xF;
+            let mut s: u32 = 0;
+            for (j, &entry) in row.iter().enumerate() {
+                // all ones if `j == v`, zero otherwise
+                let mask = (((j as u32) ^ v).wrapping_sub(1) >> 31).wrapping_neg();
+                s |= entry as u32 & mask;
+            }
+            res |= s << (4 * i);
+        }
+        res
+    }
+
     /// [Transformation](https://datatracker.ietf.org/doc/html/rfc8891.html#section-4.2)
     ///
     /// `g[k]: V_32 -> V_32`
@@ -151,6 +213,18 @@ impl Magma {
 
 }
 
+impl Drop for Magma {
+    fn drop(&mut self) {
+        self.wipe();
+    }
+}
+
+impl Rekey for Magma {
+    fn rekey(&mut self, key: [u8; 32]) {
+        self.set_key(key);
+    }
+}
+
 impl Cipher for Magma {
     type Input = u64;
     type Output = u64;
@@ -162,7 +236,7 @@ impl Cipher for Magma {
     #[inline]
     fn encrypt(&self, block_in: Self::Input) -> Result<Self::Output, CipherError> {
         // split the input block into u32 parts
-        let (mut a_1, mut a_0) = utils::u64_split(block_in);
+        let (mut a_1, mut a_0) = utils::u64_split(self.byte_order.reorder(block_in));
 
         // crypto transformations
         let mut round = 0;
@@ -172,7 +246,7 @@ impl Cipher for Magma {
         }
 
         // join u32 parts into u64 block
-        Ok(utils::u32_join(a_0, a_1))
+        Ok(self.byte_order.reorder(utils::u32_join(a_0, a_1)))
     }
 
     /// Returns [decrypted block](https://datatracker.ietf.org/doc/html/rfc8891.html#section-5.2) as `u64` value
@@ -183,7 +257,7 @@ impl Cipher for Magma {
     #[inline]
     fn decrypt(&self, block_in: Self::Output) -> Result<Self::Input, CipherError> {
         // split the input block into u32 parts
-        let (mut b_1, mut b_0) = utils::u64_split(block_in);
+        let (mut b_1, mut b_0) = utils::u64_split(self.byte_order.reorder(block_in));
 
         // crypto transformations
         let mut round = 32;
@@ -193,7 +267,19 @@ impl Cipher for Magma {
         }
 
         // join u32 parts into u64 block
-        Ok(utils::u32_join(b_0, b_1))
+        Ok(self.byte_order.reorder(utils::u32_join(b_0, b_1)))
+    }
+
+    /// Encrypts `blocks` in place, interleaving up to eight blocks per round
+    fn encrypt_blocks(&self, blocks: &mut [u64]) -> Result<(), CipherError> {
+        batch::process_blocks::<false>(&self.round_keys, self.byte_order, |k, a| self.transformation_g(k, a), blocks);
+        Ok(())
+    }
+
+    /// Decrypts `blocks` in place, interleaving up to eight blocks per round
+    fn decrypt_blocks(&self, blocks: &mut [u64]) -> Result<(), CipherError> {
+        batch::process_blocks::<true>(&self.round_keys, self.byte_order, |k, a| self.transformation_g(k, a), blocks);
+        Ok(())
     }
 }
 
@@ -206,7 +292,6 @@ mod magma_test {
     fn initialization() {
         let magma = MagmaBuilder::new()
             .set_key([0u32;8])
-            .set_round_keys([0u32; 32])
             .set_sbox(SBOX)
             .build();
 
@@ -215,6 +300,37 @@ mod magma_test {
         assert_eq!(magma.sbox, SBOX);
     } 
 
+    #[test]
+    fn missing_key_is_reported() {
+        let result = MagmaBuilder::new()
+            .set_sbox(SBOX)
+            .try_build();
+
+        assert!(matches!(result, Err(MagmaBuilderError::MissingKey)));
+    }
+
+    #[test]
+    fn sbox_defaults_to_rfc8891() {
+        use crate::test_purpose::{CIPHER_KEY, PLAINTEXT, CIPHERTEXT};
+        let magma = MagmaBuilder::new()
+            .set_key(CIPHER_KEY)
+            .try_build()
+            .unwrap();
+
+        assert_eq!(magma.sbox, SBOX);
+        assert_eq!(magma.encrypt(PLAINTEXT).unwrap(), CIPHERTEXT);
+    }
+
+    #[test]
+    fn round_keys_rfc8891() {
+        use crate::test_purpose::{CIPHER_KEY, ROUND_KEYS};
+        let magma = MagmaBuilder::new()
+            .set_key(CIPHER_KEY)
+            .build();
+
+        assert_eq!(magma.round_keys, ROUND_KEYS);
+    }
+
     #[test]
     fn encrypt_rfc8891() {
         // Test vectors RFC8891:
@@ -240,6 +356,205 @@ mod magma_test {
         assert_eq!(magma.decrypt(CIPHERTEXT).unwrap(), PLAINTEXT);
     }
 
+    #[test]
+    fn byte_key_rfc8891() {
+        use crate::test_purpose::{CIPHER_KEY, CIPHER_KEY_U8_ARRAY, ROUND_KEYS, PLAINTEXT, CIPHERTEXT};
+        let magma = MagmaBuilder::new()
+            .set_key(CIPHER_KEY_U8_ARRAY)
+            .build();
+
+        assert_eq!(magma.key, CIPHER_KEY);
+        assert_eq!(magma.round_keys, ROUND_KEYS);
+        assert_eq!(magma.encrypt(PLAINTEXT).unwrap(), CIPHERTEXT);
+    }
+
+    #[test]
+    fn byte_slice_key() {
+        use crate::test_purpose::{CIPHER_KEY, CIPHER_KEY_U8_ARRAY};
+        let key = Key::try_from(&CIPHER_KEY_U8_ARRAY[..]).unwrap();
+        let mut magma = MagmaBuilder::default().build();
+        magma.set_key(key);
+
+        assert_eq!(magma.key, CIPHER_KEY);
+        assert!(Key::try_from(&CIPHER_KEY_U8_ARRAY[..31]).is_err());
+        assert!(Key::try_from(&[0u8; 33][..]).is_err());
+    }
+
+    #[test]
+    fn param_set_is_selected() {
+        use crate::core::cipher::magma::param_set::SboxParamSet;
+        use crate::test_purpose::{CIPHER_KEY, PLAINTEXT, CIPHERTEXT};
+        let tc26 = MagmaBuilder::new()
+            .set_key(CIPHER_KEY)
+            .set_param_set(SboxParamSet::Tc26Z)
+            .build();
+        let crypto_pro = MagmaBuilder::new()
+            .set_key(CIPHER_KEY)
+            .set_param_set(SboxParamSet::CryptoProA)
+            .build();
+
+        assert_eq!(tc26.encrypt(PLAINTEXT).unwrap(), CIPHERTEXT);
+        assert_eq!(crypto_pro.sbox, SBOX_CRYPTOPRO_A);
+        assert_ne!(crypto_pro.encrypt(PLAINTEXT).unwrap(), CIPHERTEXT);
+        assert_eq!(crypto_pro.decrypt(crypto_pro.encrypt(PLAINTEXT).unwrap()).unwrap(), PLAINTEXT);
+    }
+
+    #[test]
+    fn gost28147_byte_order() {
+        // GOST 28147-89 с таблицей Z совпадает с Магмой с точностью до порядка байтов:
+        // слова ключа little-endian, блок развернут
+        use crate::test_purpose::{CIPHER_KEY, PLAINTEXT, CIPHERTEXT};
+        let mut key = [0u8; 32];
+        for (chunk, word) in key.chunks_exact_mut(4).zip(CIPHER_KEY) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+
+        let mut magma = MagmaBuilder::new()
+            .set_key(key)
+            .set_byte_order(ByteOrder::Gost28147)
+            .build();
+
+        assert_eq!(magma.key, CIPHER_KEY);
+        assert_eq!(magma.encrypt(PLAINTEXT.swap_bytes()).unwrap(), CIPHERTEXT.swap_bytes());
+        assert_eq!(magma.decrypt(CIPHERTEXT.swap_bytes()).unwrap(), PLAINTEXT.swap_bytes());
+
+        // Replacing the key keeps the byte order
+        magma.set_key(key);
+        assert_eq!(magma.key, CIPHER_KEY);
+    }
+
+    #[test]
+    fn wipe_clears_key_material() {
+        use crate::test_purpose::CIPHER_KEY;
+        let mut builder = MagmaBuilder::new();
+        builder.set_key(CIPHER_KEY);
+        let mut magma = builder.build();
+
+        builder.wipe();
+        magma.wipe();
+
+        assert!(builder.key.is_none());
+        assert!(matches!(builder.try_build(), Err(MagmaBuilderError::MissingKey)));
+        assert_eq!(magma.key, [0u32; 8]);
+        assert_eq!(magma.round_keys, [0u32; 32]);
+        assert_eq!(magma.sbox, SBOX);
+    }
+
+    #[test]
+    fn wiped_cipher_can_be_rekeyed() {
+        use crate::test_purpose::{CIPHER_KEY, PLAINTEXT, CIPHERTEXT};
+        let mut magma = MagmaBuilder::new()
+            .set_key(CIPHER_KEY)
+            .build();
+
+        magma.wipe();
+        magma.set_key(CIPHER_KEY);
+        assert_eq!(magma.encrypt(PLAINTEXT).unwrap(), CIPHERTEXT);
+        assert_eq!(magma.decrypt(CIPHERTEXT).unwrap(), PLAINTEXT);
+    }
+
+    #[test]
+    fn drop_clears_key_material() {
+        use core::mem::{ManuallyDrop, MaybeUninit};
+        use core::ptr::addr_of;
+        use crate::test_purpose::CIPHER_KEY;
+
+        let mut slot = MaybeUninit::new(MagmaBuilder::new().set_key(CIPHER_KEY).build());
+        // SAFETY: the slot is initialized; after the drop only plain integer
+        // fields are read, their storage is still owned by the slot
+        unsafe {
+            slot.assume_init_drop();
+            let magma = slot.as_ptr();
+            assert_eq!(addr_of!((*magma).key).read(), [0u32; 8]);
+            assert_eq!(addr_of!((*magma).round_keys).read(), [0u32; 32]);
+        }
+
+        let mut slot = MaybeUninit::new(MagmaBuilder::new());
+        // SAFETY: same as above, the key is copied out by value and never dropped again
+        unsafe {
+            slot.assume_init_mut().set_key(CIPHER_KEY);
+            slot.assume_init_drop();
+            let key = ManuallyDrop::new(addr_of!((*slot.as_ptr()).key).read());
+            assert!(key.is_none());
+        }
+    }
+
+    #[test]
+    fn replaced_keys_are_wiped() {
+        use core::mem::{ManuallyDrop, MaybeUninit};
+        use crate::test_purpose::{CIPHER_KEY, CIPHER_KEY_U8_ARRAY, KUZNYECHIK_KEY, PLAINTEXT, CIPHERTEXT};
+
+        // The previous key is dropped, and so wiped, in place of the new one
+        let mut builder = MagmaBuilder::new();
+        builder.set_key(KUZNYECHIK_KEY).set_key(CIPHER_KEY);
+        let mut magma = builder.build();
+        assert_eq!(magma.key, CIPHER_KEY);
+
+        magma.set_key(KUZNYECHIK_KEY);
+        magma.set_key(CIPHER_KEY_U8_ARRAY);
+        assert_eq!(magma.key, CIPHER_KEY);
+        assert_eq!(magma.encrypt(PLAINTEXT).unwrap(), CIPHERTEXT);
+
+        let mut slot = MaybeUninit::new(Key::from(CIPHER_KEY_U8_ARRAY));
+        // SAFETY: the slot is initialized; after the drop the key holds only
+        // plain integers, it is copied out by value and never dropped again
+        unsafe {
+            slot.assume_init_drop();
+            let key = ManuallyDrop::new(slot.as_ptr().read());
+            assert!(matches!(*key, Key::U8(bytes) if bytes == [0u8; 32]));
+        }
+    }
+
+    #[test]
+    fn transformation_t_rfc8891() {
+        use crate::test_purpose::TRANSFORMATION_T;
+        let magma = MagmaBuilder::default().build();
+
+        for (input, output) in TRANSFORMATION_T {
+            assert_eq!(magma.transformation_t_reference(input), output);
+            assert_eq!(magma.transformation_t_constant_time(input), output);
+        }
+    }
+
+    #[test]
+    fn constant_time_matches_reference() {
+        use crate::core::cipher::magma::param_set::SboxParamSet;
+        use crate::test_purpose::XorShift64;
+        let mut rng = XorShift64(0x9E37_79B9_7F4A_7C15);
+
+        for set in [SboxParamSet::Tc26Z, SboxParamSet::Test, SboxParamSet::CryptoProA] {
+            let magma = MagmaBuilder::new()
+                .set_key([0u32; 8])
+                .set_param_set(set)
+                .build();
+
+            for _ in 0..4096 {
+                let a = rng.next_u64() as u32;
+                assert_eq!(magma.transformation_t_constant_time(a), magma.transformation_t_reference(a));
+            }
+        }
+
+        // Whole blocks, `encrypt` uses the implementation selected by the feature
+        let encrypt_with = |magma: &Magma, t: fn(&Magma, u32) -> u32, block: u64| {
+            let (mut a_1, mut a_0) = utils::u64_split(block);
+            for &k in magma.round_keys.iter() {
+                let g = t(magma, a_0.wrapping_add(k)).rotate_left(11);
+                (a_1, a_0) = (a_0, g ^ a_1);
+            }
+            utils::u32_join(a_0, a_1)
+        };
+
+        let magma = MagmaBuilder::new()
+            .set_key([rng.next_u64() as u32; 8])
+            .build();
+        for _ in 0..1024 {
+            let block = rng.next_u64();
+            let expected = magma.encrypt(block).unwrap();
+            assert_eq!(encrypt_with(&magma, Magma::transformation_t_reference, block), expected);
+            assert_eq!(encrypt_with(&magma, Magma::transformation_t_constant_time, block), expected);
+        }
+    }
+
     #[test]
     fn correctness() {
         let magma = MagmaBuilder::default().build();
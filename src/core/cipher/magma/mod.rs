@@ -1,4 +1,8 @@
 pub mod consts;
 pub mod utils; 
+#[allow(clippy::module_inception)]
 pub mod magma;
 pub mod key;
+pub mod param_set;
+pub mod fast;
+pub(crate) mod batch;
@@ -0,0 +1,125 @@
+//! Parameters needed to interoperate with GOST 28147-89 equipment
+
+use crate::core::cipher::magma::consts::*;
+
+/// Named substitution boxes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SboxParamSet {
+    /// id-tc26-gost-28147-param-Z, the only set allowed by GOST R 34.12-2015
+    #[default]
+    Tc26Z,
+    /// id-Gost28147-89-TestParamSet
+    Test,
+    /// id-Gost28147-89-CryptoPro-A-ParamSet
+    CryptoProA,
+    /// id-Gost28147-89-CryptoPro-B-ParamSet
+    CryptoProB,
+    /// id-Gost28147-89-CryptoPro-C-ParamSet
+    CryptoProC,
+    /// id-Gost28147-89-CryptoPro-D-ParamSet
+    CryptoProD,
+}
+
+impl SboxParamSet {
+    /// Returns the table in the layout expected by `MagmaBuilder::set_sbox`
+    pub const fn sbox(self) -> [u8; 128] {
+        match self {
+            Self::Tc26Z => SBOX,
+            Self::Test => SBOX_TEST,
+            Self::CryptoProA => SBOX_CRYPTOPRO_A,
+            Self::CryptoProB => SBOX_CRYPTOPRO_B,
+            Self::CryptoProC => SBOX_CRYPTOPRO_C,
+            Self::CryptoProD => SBOX_CRYPTOPRO_D,
+        }
+    }
+}
+
+impl From<SboxParamSet> for [u8; 128] {
+    fn from(value: SboxParamSet) -> Self {
+        value.sbox()
+    }
+}
+
+/// How bytes of keys and blocks are mapped to cipher words
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ByteOrder {
+    /// [RFC 8891](https://datatracker.ietf.org/doc/html/rfc8891.html#section-4.3):
+    /// words are big-endian, `K_1` is made of the first four key bytes
+    #[default]
+    Rfc8891,
+    /// GOST 28147-89 as implemented by legacy equipment:
+    /// key words are little-endian and the block is processed byte-reversed,
+    /// so that `N_1` is made of the first four bytes on the wire
+    Gost28147,
+}
+
+impl ByteOrder {
+    /// Maps block between wire and RFC 8891 order, the mapping is an involution
+    #[inline]
+    pub(crate) fn reorder(self, block: u64) -> u64 {
+        match self {
+            Self::Rfc8891 => block,
+            Self::Gost28147 => block.swap_bytes(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [SboxParamSet; 6] = [
+        SboxParamSet::Tc26Z,
+        SboxParamSet::Test,
+        SboxParamSet::CryptoProA,
+        SboxParamSet::CryptoProB,
+        SboxParamSet::CryptoProC,
+        SboxParamSet::CryptoProD,
+    ];
+
+    #[test]
+    fn every_row_is_permutation() {
+        for set in ALL {
+            let sbox = set.sbox();
+            for row in sbox.chunks_exact(16) {
+                let mut seen = [false; 16];
+                row.iter().for_each(|&v| seen[v as usize] = true);
+                assert!(seen.iter().all(|&s| s), "{:?}", set);
+            }
+        }
+    }
+
+    #[test]
+    fn tables_are_distinct() {
+        for (i, a) in ALL.iter().enumerate() {
+            for b in &ALL[i + 1..] {
+                assert_ne!(a.sbox(), b.sbox());
+            }
+        }
+    }
+
+    #[test]
+    fn default_is_rfc8891() {
+        assert_eq!(SboxParamSet::default().sbox(), SBOX);
+        assert_eq!(ByteOrder::default(), ByteOrder::Rfc8891);
+    }
+
+    #[test]
+    fn gost28147_test_param_set() {
+        use crate::core::cipher::magma::magma::MagmaBuilder;
+        use crate::core::cipher::Cipher;
+        use crate::test_purpose::{GOST28147_TEST_KEY, GOST28147_TEST_CIPHERTEXT};
+
+        // Первый шаг примера ГОСТ Р 34.11-94: нулевой блок на ключе K_1
+        let gost = MagmaBuilder::new()
+            .set_key(GOST28147_TEST_KEY)
+            .set_param_set(SboxParamSet::Test)
+            .set_byte_order(ByteOrder::Gost28147)
+            .build();
+
+        let ciphertext = u64::from_be_bytes(GOST28147_TEST_CIPHERTEXT);
+        assert_eq!(gost.encrypt(0).unwrap(), ciphertext);
+        assert_eq!(gost.decrypt(ciphertext).unwrap(), 0);
+    }
+}
+
@@ -1,4 +1,6 @@
+pub mod kuznyechik;
 pub mod magma;
+pub mod modes;
 
 #[derive(Debug)]
 pub enum CipherError {
@@ -12,4 +14,60 @@ pub trait Cipher {
 
     fn encrypt(&self, data: Self::Input) -> Result<Self::Output, CipherError>;
     fn decrypt(&self, data: Self::Output) -> Result<Self::Input, CipherError>;
+
+    /// Encrypts independent `blocks` in place.
+    /// Ciphers may override it to process several blocks at once
+    fn encrypt_blocks(&self, blocks: &mut [Self::Input]) -> Result<(), CipherError>
+    where
+        Self::Input: Copy + From<Self::Output>,
+    {
+        for block in blocks.iter_mut() {
+            *block = self.encrypt(*block)?.into();
+        }
+        Ok(())
+    }
+
+    /// Decrypts independent `blocks` in place
+    fn decrypt_blocks(&self, blocks: &mut [Self::Output]) -> Result<(), CipherError>
+    where
+        Self::Output: Copy + From<Self::Input>,
+    {
+        for block in blocks.iter_mut() {
+            *block = self.decrypt(*block)?.into();
+        }
+        Ok(())
+    }
+}
+
+/// Ciphers with a 256-bit key which can be replaced in place, as needed by key meshing
+pub trait Rekey {
+    /// Replaces the key, `key` is given as bytes in the cipher's usual order
+    fn rekey(&mut self, key: [u8; 32]);
+}
+
+impl<C: Cipher + ?Sized> Cipher for &C {
+    type Input = C::Input;
+    type Output = C::Output;
+
+    fn encrypt(&self, data: Self::Input) -> Result<Self::Output, CipherError> {
+        (**self).encrypt(data)
+    }
+
+    fn decrypt(&self, data: Self::Output) -> Result<Self::Input, CipherError> {
+        (**self).decrypt(data)
+    }
+
+    fn encrypt_blocks(&self, blocks: &mut [Self::Input]) -> Result<(), CipherError>
+    where
+        Self::Input: Copy + From<Self::Output>,
+    {
+        (**self).encrypt_blocks(blocks)
+    }
+
+    fn decrypt_blocks(&self, blocks: &mut [Self::Output]) -> Result<(), CipherError>
+    where
+        Self::Output: Copy + From<Self::Input>,
+    {
+        (**self).decrypt_blocks(blocks)
+    }
 }
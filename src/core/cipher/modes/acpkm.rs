@@ -0,0 +1,103 @@
+//! [ACPKM re-keying](https://datatracker.ietf.org/doc/html/rfc8645.html#section-6.2), R 1323565.1.017-2018
+//!
+//! The key of every next section is `ACPKM(K) = MSB_k(E_K(D_1) || ... || E_K(D_J))`,
+//! where `D_1 || ... || D_J = 80 || 81 || ... || 9F`.
+
+use super::*;
+use crate::core::cipher::{Cipher, Rekey};
+use crate::core::zeroize::Zeroize;
+
+/// Key length `k` in bytes
+pub(crate) const KEY_SIZE: usize = 32;
+
+/// Returns `ACPKM(K)`, where `K` is the current key of `cipher`
+pub fn acpkm<C, B>(cipher: &C) -> Result<[u8; KEY_SIZE], ModeError>
+where
+    B: Block,
+    C: Cipher<Input = B, Output = B>,
+{
+    let mut key = [0u8; KEY_SIZE];
+    for (i, chunk) in key.chunks_mut(B::SIZE).enumerate() {
+        let mut d = B::Bytes::default();
+        for (j, byte) in d.as_mut().iter_mut().enumerate() {
+            *byte = 0x80 + (i * B::SIZE + j) as u8;
+        }
+
+        let block = cipher.encrypt(B::from_block_bytes(d))?;
+        chunk.copy_from_slice(block.to_block_bytes().as_ref());
+    }
+    Ok(key)
+}
+
+/// Gamma of [CTR-ACPKM](https://datatracker.ietf.org/doc/html/rfc8645.html#section-6.2.2):
+/// the counter runs through the whole message, the key is replaced
+/// with `ACPKM(K)` every `section_blocks` blocks
+pub(crate) struct AcpkmGamma<C, B> {
+    cipher: C,
+    counter: B,
+    section_blocks: usize,
+    used: usize,
+}
+
+impl<C, B> AcpkmGamma<C, B>
+where
+    B: Block,
+    C: Cipher<Input = B, Output = B> + Rekey,
+{
+    pub(crate) fn new(cipher: C, counter: B, section_blocks: usize) -> Self {
+        Self { cipher, counter, section_blocks, used: 0 }
+    }
+
+    /// Fills `blocks` with the next blocks of gamma
+    pub(crate) fn fill(&mut self, blocks: &mut [B]) -> Result<(), ModeError> {
+        let mut rest = blocks;
+        while !rest.is_empty() {
+            if self.used == self.section_blocks {
+                let mut key = acpkm(&self.cipher)?;
+                self.cipher.rekey(key);
+                key.zeroize();
+                self.used = 0;
+            }
+
+            let (head, tail) = rest.split_at_mut(rest.len().min(self.section_blocks - self.used));
+            for block in head.iter_mut() {
+                *block = self.counter;
+                self.counter = self.counter.increment();
+            }
+            self.cipher.encrypt_blocks(head)?;
+
+            self.used += head.len();
+            rest = tail;
+        }
+        Ok(())
+    }
+}
+
+/// Returns number of blocks in a section of `section_size` bytes
+pub(crate) fn section_blocks<B: Block>(section_size: usize) -> Result<usize, ModeError> {
+    if section_size == 0 || !section_size.is_multiple_of(B::SIZE) {
+        return Err(ModeError::InvalidSectionSize);
+    }
+    Ok(section_size / B::SIZE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cipher::magma::magma::MagmaBuilder;
+    use crate::test_purpose::*;
+
+    #[test]
+    fn acpkm_rfc8645() {
+        let magma = MagmaBuilder::new().set_key(KUZNYECHIK_KEY).build();
+        assert_eq!(acpkm(&magma).unwrap(), ACPKM_MAGMA_K2);
+    }
+
+    #[test]
+    fn section_size_must_be_whole_blocks() {
+        assert!(matches!(section_blocks::<u64>(0), Err(ModeError::InvalidSectionSize)));
+        assert!(matches!(section_blocks::<u64>(12), Err(ModeError::InvalidSectionSize)));
+        assert!(matches!(section_blocks::<u128>(24), Err(ModeError::InvalidSectionSize)));
+        assert_eq!(section_blocks::<u64>(16).unwrap(), 2);
+    }
+}
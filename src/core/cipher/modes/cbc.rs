@@ -0,0 +1,95 @@
+//! [Cipher Block Chaining Mode](https://www.tc26.ru/standard/gost/GOST_R_3413-2015.pdf), Section 5.4
+
+use super::*;
+use crate::core::cipher::Cipher;
+
+/// `Cbc` with a shift register of `Z` blocks
+pub struct Cbc<C> {
+    cipher: C,
+}
+
+impl<C> Cbc<C> {
+    pub fn new(cipher: C) -> Self {
+        Self { cipher }
+    }
+}
+
+impl<C, B> Cbc<C>
+where
+    B: Block,
+    C: Cipher<Input = B, Output = B>,
+{
+    /// Encrypts `data` in place
+    ///
+    /// # Arguments
+    ///
+    /// * `iv` - initializing value of `Z` blocks, the first block is the most significant
+    /// * `data` - plaintext, its length must be a multiple of the block size
+    pub fn encrypt<const Z: usize>(&self, iv: [B; Z], data: &mut [u8]) -> Result<(), ModeError> {
+        if !data.len().is_multiple_of(B::SIZE) {
+            return Err(ModeError::InvalidDataLength);
+        }
+
+        let mut register = iv;
+        for (i, chunk) in data.chunks_mut(B::SIZE).enumerate() {
+            let block = self.cipher.encrypt(B::from_be_slice(chunk) ^ register[i % Z])?;
+            register[i % Z] = block;
+            chunk.copy_from_slice(block.to_block_bytes().as_ref());
+        }
+        Ok(())
+    }
+
+    /// Decrypts `data` in place
+    ///
+    /// # Arguments
+    ///
+    /// * `iv` - initializing value of `Z` blocks, the first block is the most significant
+    /// * `data` - ciphertext, its length must be a multiple of the block size
+    pub fn decrypt<const Z: usize>(&self, iv: [B; Z], data: &mut [u8]) -> Result<(), ModeError> {
+        if !data.len().is_multiple_of(B::SIZE) {
+            return Err(ModeError::InvalidDataLength);
+        }
+
+        let mut register = iv;
+        for (i, chunk) in data.chunks_mut(B::SIZE).enumerate() {
+            let block = B::from_be_slice(chunk);
+            let plain = self.cipher.decrypt(block)? ^ register[i % Z];
+            register[i % Z] = block;
+            chunk.copy_from_slice(plain.to_block_bytes().as_ref());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cipher::magma::magma::MagmaBuilder;
+    use crate::test_purpose::*;
+
+    #[test]
+    fn encrypt_gost_r_34_13() {
+        let cbc = Cbc::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut data = plaintext_bytes();
+
+        cbc.encrypt(CBC_IV, &mut data).unwrap();
+        assert_eq!(data, blocks_to_bytes(&CBC_CIPHERTEXT));
+    }
+
+    #[test]
+    fn decrypt_gost_r_34_13() {
+        let cbc = Cbc::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut data = blocks_to_bytes(&CBC_CIPHERTEXT);
+
+        cbc.decrypt(CBC_IV, &mut data).unwrap();
+        assert_eq!(data, plaintext_bytes());
+    }
+
+    #[test]
+    fn partial_block_is_rejected() {
+        let cbc = Cbc::new(MagmaBuilder::default().build());
+        let mut data = [0u8; 7];
+
+        assert!(matches!(cbc.encrypt([0u64], &mut data), Err(ModeError::InvalidDataLength)));
+    }
+}
@@ -0,0 +1,96 @@
+//! [Cipher Feedback Mode](https://www.tc26.ru/standard/gost/GOST_R_3413-2015.pdf), Section 5.5
+//!
+//! Implemented with `s = n`: the whole ciphertext block is fed back. The last
+//! block of the message may be incomplete.
+
+use super::*;
+use crate::core::cipher::Cipher;
+
+/// `Cfb` with a shift register of `Z` blocks
+pub struct Cfb<C> {
+    cipher: C,
+}
+
+impl<C> Cfb<C> {
+    pub fn new(cipher: C) -> Self {
+        Self { cipher }
+    }
+}
+
+impl<C, B> Cfb<C>
+where
+    B: Block,
+    C: Cipher<Input = B, Output = B>,
+{
+    /// Encrypts `data` in place
+    ///
+    /// # Arguments
+    ///
+    /// * `iv` - initializing value of `Z` blocks, the first block is the most significant
+    /// * `data` - plaintext of any length
+    pub fn encrypt<const Z: usize>(&self, iv: [B; Z], data: &mut [u8]) -> Result<(), ModeError> {
+        let mut register = iv;
+        for (i, chunk) in data.chunks_mut(B::SIZE).enumerate() {
+            xor_in_place(chunk, self.cipher.encrypt(register[i % Z])?);
+            if chunk.len() == B::SIZE {
+                register[i % Z] = B::from_be_slice(chunk);
+            }
+        }
+        Ok(())
+    }
+
+    /// Decrypts `data` in place
+    ///
+    /// # Arguments
+    ///
+    /// * `iv` - initializing value of `Z` blocks, the first block is the most significant
+    /// * `data` - ciphertext of any length
+    pub fn decrypt<const Z: usize>(&self, iv: [B; Z], data: &mut [u8]) -> Result<(), ModeError> {
+        let mut register = iv;
+        for (i, chunk) in data.chunks_mut(B::SIZE).enumerate() {
+            let gamma = self.cipher.encrypt(register[i % Z])?;
+            if chunk.len() == B::SIZE {
+                register[i % Z] = B::from_be_slice(chunk);
+            }
+            xor_in_place(chunk, gamma);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cipher::magma::magma::MagmaBuilder;
+    use crate::test_purpose::*;
+
+    #[test]
+    fn encrypt_gost_r_34_13() {
+        let cfb = Cfb::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut data = plaintext_bytes();
+
+        cfb.encrypt(CFB_IV, &mut data).unwrap();
+        assert_eq!(data, blocks_to_bytes(&CFB_CIPHERTEXT));
+    }
+
+    #[test]
+    fn decrypt_gost_r_34_13() {
+        let cfb = Cfb::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut data = blocks_to_bytes(&CFB_CIPHERTEXT);
+
+        cfb.decrypt(CFB_IV, &mut data).unwrap();
+        assert_eq!(data, plaintext_bytes());
+    }
+
+    #[test]
+    fn partial_last_block_roundtrip() {
+        let cfb = Cfb::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut data = [0u8; 21];
+        data.copy_from_slice(&plaintext_bytes()[..21]);
+
+        cfb.encrypt(CFB_IV, &mut data).unwrap();
+        assert_eq!(data[..], blocks_to_bytes(&CFB_CIPHERTEXT)[..21]);
+        cfb.decrypt(CFB_IV, &mut data).unwrap();
+        assert_eq!(data[..], plaintext_bytes()[..21]);
+    }
+}
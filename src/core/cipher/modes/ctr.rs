@@ -0,0 +1,119 @@
+//! [Counter Encryption Mode](https://www.tc26.ru/standard/gost/GOST_R_3413-2015.pdf), Section 5.2
+
+use super::*;
+use crate::core::cipher::Cipher;
+
+/// `Ctr` turns the block cipher into a stream cipher.
+/// The last block of the message may be incomplete.
+pub struct Ctr<C> {
+    cipher: C,
+}
+
+impl<C> Ctr<C> {
+    pub fn new(cipher: C) -> Self {
+        Self { cipher }
+    }
+}
+
+impl<C, B> Ctr<C>
+where
+    B: Block,
+    C: Cipher<Input = B, Output = B>,
+{
+    /// XORs `data` in place with the gamma produced from `iv`
+    ///
+    /// # Arguments
+    ///
+    /// * `iv` - initializing value of half the block size, must be unique for every message
+    /// * `data` - plaintext or ciphertext of any length
+    pub fn apply_gamma(&self, iv: B::Half, data: &mut [u8]) -> Result<(), ModeError> {
+        let mut counter = B::from_half(iv);
+        let mut gamma = [counter; BATCH];
+        for chunk in data.chunks_mut(B::SIZE * BATCH) {
+            let gamma = &mut gamma[..chunk.len().div_ceil(B::SIZE)];
+            for block in gamma.iter_mut() {
+                *block = counter;
+                counter = counter.increment();
+            }
+
+            self.cipher.encrypt_blocks(gamma)?;
+
+            for (bytes, &block) in chunk.chunks_mut(B::SIZE).zip(gamma.iter()) {
+                xor_in_place(bytes, block);
+            }
+        }
+        Ok(())
+    }
+
+    /// Encrypts `data` in place
+    pub fn encrypt(&self, iv: B::Half, data: &mut [u8]) -> Result<(), ModeError> {
+        self.apply_gamma(iv, data)
+    }
+
+    /// Decrypts `data` in place
+    pub fn decrypt(&self, iv: B::Half, data: &mut [u8]) -> Result<(), ModeError> {
+        self.apply_gamma(iv, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cipher::kuznyechik::kuznyechik::KuznyechikBuilder;
+    use crate::core::cipher::magma::magma::MagmaBuilder;
+    use crate::test_purpose::*;
+
+    #[test]
+    fn encrypt_gost_r_34_13() {
+        let ctr = Ctr::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut data = plaintext_bytes();
+
+        ctr.encrypt(CTR_IV, &mut data).unwrap();
+        assert_eq!(data, blocks_to_bytes(&CTR_CIPHERTEXT));
+    }
+
+    #[test]
+    fn decrypt_gost_r_34_13() {
+        let ctr = Ctr::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut data = blocks_to_bytes(&CTR_CIPHERTEXT);
+
+        ctr.decrypt(CTR_IV, &mut data).unwrap();
+        assert_eq!(data, plaintext_bytes());
+    }
+
+    #[test]
+    fn partial_last_block() {
+        let ctr = Ctr::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut data = [0u8; 29];
+        data.copy_from_slice(&plaintext_bytes()[..29]);
+
+        ctr.encrypt(CTR_IV, &mut data).unwrap();
+        assert_eq!(data[..], blocks_to_bytes(&CTR_CIPHERTEXT)[..29]);
+    }
+
+    #[test]
+    fn kuznyechik_gost_r_34_13() {
+        let ctr = Ctr::new(KuznyechikBuilder::new().set_key(KUZNYECHIK_KEY).build());
+        let mut data = kuznyechik_blocks_to_bytes(&KUZNYECHIK_MODES_PLAINTEXT);
+
+        ctr.encrypt(KUZNYECHIK_CTR_IV, &mut data).unwrap();
+        assert_eq!(data, kuznyechik_blocks_to_bytes(&KUZNYECHIK_CTR_CIPHERTEXT));
+    }
+
+    #[test]
+    fn batches_match_single_blocks() {
+        let magma = MagmaBuilder::default().set_key(CIPHER_KEY).build();
+        let ctr = Ctr::new(&magma);
+        let mut data = [0u8; 8 * 19 + 5];
+        data.iter_mut().enumerate().for_each(|(i, b)| *b = i as u8);
+        let mut expected = data;
+
+        ctr.encrypt(CTR_IV, &mut data).unwrap();
+
+        let first = (CTR_IV as u64) << 32;
+        for (counter, chunk) in (first..).zip(expected.chunks_mut(8)) {
+            xor_in_place(chunk, magma.encrypt(counter).unwrap());
+        }
+        assert_eq!(data, expected);
+    }
+}
@@ -0,0 +1,134 @@
+//! [Counter Encryption Mode with ACPKM re-keying](https://datatracker.ietf.org/doc/html/rfc8645.html#section-6.2.2), R 1323565.1.017-2018
+//!
+//! The message is split into sections of `N` bytes, every next section is
+//! encrypted under `ACPKM` of the previous section key.
+
+use super::acpkm::{section_blocks, AcpkmGamma};
+use super::*;
+use crate::core::cipher::{Cipher, Rekey};
+
+/// `CtrAcpkm` is `Ctr` which changes the key after every section
+pub struct CtrAcpkm<C> {
+    cipher: C,
+    section_blocks: usize,
+}
+
+impl<C, B> CtrAcpkm<C>
+where
+    B: Block,
+    C: Cipher<Input = B, Output = B> + Rekey + Clone,
+{
+    /// Constructs mode, `cipher` holds the initial key of every message
+    ///
+    /// # Arguments
+    ///
+    /// * `cipher` - cipher keyed with `K^1`
+    /// * `section_size` - section size `N` in bytes, a positive multiple of the block size
+    pub fn new(cipher: C, section_size: usize) -> Result<Self, ModeError> {
+        Ok(Self {
+            cipher,
+            section_blocks: section_blocks::<B>(section_size)?,
+        })
+    }
+
+    /// XORs `data` in place with the gamma produced from `iv`
+    ///
+    /// # Arguments
+    ///
+    /// * `iv` - initializing value of half the block size, must be unique for every message
+    /// * `data` - plaintext or ciphertext of any length
+    pub fn apply_gamma(&self, iv: B::Half, data: &mut [u8]) -> Result<(), ModeError> {
+        let mut gamma = AcpkmGamma::new(self.cipher.clone(), B::from_half(iv), self.section_blocks);
+
+        let mut blocks = [zero_block::<B>(); BATCH];
+        for chunk in data.chunks_mut(B::SIZE * BATCH) {
+            let blocks = &mut blocks[..chunk.len().div_ceil(B::SIZE)];
+            gamma.fill(blocks)?;
+
+            for (bytes, &block) in chunk.chunks_mut(B::SIZE).zip(blocks.iter()) {
+                xor_in_place(bytes, block);
+            }
+        }
+        Ok(())
+    }
+
+    /// Encrypts `data` in place
+    pub fn encrypt(&self, iv: B::Half, data: &mut [u8]) -> Result<(), ModeError> {
+        self.apply_gamma(iv, data)
+    }
+
+    /// Decrypts `data` in place
+    pub fn decrypt(&self, iv: B::Half, data: &mut [u8]) -> Result<(), ModeError> {
+        self.apply_gamma(iv, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cipher::kuznyechik::kuznyechik::KuznyechikBuilder;
+    use crate::core::cipher::magma::magma::MagmaBuilder;
+    use crate::test_purpose::*;
+
+    #[test]
+    fn magma_rfc8645() {
+        let ctr = CtrAcpkm::new(MagmaBuilder::new().set_key(KUZNYECHIK_KEY).build(), 16).unwrap();
+        let mut data = ACPKM_PLAINTEXT;
+
+        ctr.encrypt(CTR_IV, &mut data).unwrap();
+        assert_eq!(data, MAGMA_CTR_ACPKM_CIPHERTEXT);
+        ctr.decrypt(CTR_IV, &mut data).unwrap();
+        assert_eq!(data, ACPKM_PLAINTEXT);
+    }
+
+    #[test]
+    fn kuznyechik_rfc8645() {
+        let ctr = CtrAcpkm::new(KuznyechikBuilder::new().set_key(KUZNYECHIK_KEY).build(), 32).unwrap();
+        let mut data = ACPKM_PLAINTEXT;
+
+        ctr.encrypt(KUZNYECHIK_CTR_IV, &mut data).unwrap();
+        assert_eq!(data, KUZNYECHIK_CTR_ACPKM_CIPHERTEXT);
+    }
+
+    #[test]
+    fn roundtrip_across_sections() {
+        let ctr = CtrAcpkm::new(MagmaBuilder::new().set_key(CIPHER_KEY).build(), 24).unwrap();
+        let mut data = [0u8; 8 * 19 + 3];
+        data.iter_mut().enumerate().for_each(|(i, b)| *b = i as u8);
+        let plaintext = data;
+
+        ctr.encrypt(CTR_IV, &mut data).unwrap();
+        assert_ne!(data, plaintext);
+        ctr.decrypt(CTR_IV, &mut data).unwrap();
+        assert_eq!(data, plaintext);
+    }
+
+    #[test]
+    fn single_section_is_plain_ctr() {
+        let ctr = CtrAcpkm::new(MagmaBuilder::new().set_key(CIPHER_KEY).build(), 64).unwrap();
+        let mut data = plaintext_bytes();
+
+        ctr.encrypt(CTR_IV, &mut data).unwrap();
+        assert_eq!(data, blocks_to_bytes(&CTR_CIPHERTEXT));
+    }
+
+    #[test]
+    fn kuznyechik_roundtrip() {
+        let kuznyechik = KuznyechikBuilder::new().set_key(KUZNYECHIK_KEY).build();
+        let ctr = CtrAcpkm::new(kuznyechik, 32).unwrap();
+        let mut data = kuznyechik_blocks_to_bytes(&KUZNYECHIK_MODES_PLAINTEXT);
+
+        ctr.encrypt(KUZNYECHIK_CTR_IV, &mut data).unwrap();
+        assert_eq!(data[..32], kuznyechik_blocks_to_bytes(&KUZNYECHIK_CTR_CIPHERTEXT)[..32]);
+        assert_ne!(data[32..], kuznyechik_blocks_to_bytes(&KUZNYECHIK_CTR_CIPHERTEXT)[32..]);
+
+        ctr.decrypt(KUZNYECHIK_CTR_IV, &mut data).unwrap();
+        assert_eq!(data, kuznyechik_blocks_to_bytes(&KUZNYECHIK_MODES_PLAINTEXT));
+    }
+
+    #[test]
+    fn invalid_section_size() {
+        let magma = MagmaBuilder::default().build();
+        assert!(matches!(CtrAcpkm::new(magma, 20), Err(ModeError::InvalidSectionSize)));
+    }
+}
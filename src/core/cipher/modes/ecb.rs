@@ -0,0 +1,136 @@
+//! [Electronic Codebook](https://www.tc26.ru/standard/gost/GOST_R_3413-2015.pdf), Section 5.1
+
+use super::*;
+use crate::core::cipher::Cipher;
+
+/// `Ecb` encrypts every block independently
+pub struct Ecb<C> {
+    cipher: C,
+}
+
+impl<C> Ecb<C> {
+    pub fn new(cipher: C) -> Self {
+        Self { cipher }
+    }
+}
+
+impl<C, B> Ecb<C>
+where
+    B: Block,
+    C: Cipher<Input = B, Output = B>,
+{
+    /// Encrypts `data` in place
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - plaintext, its length must be a multiple of the block size
+    pub fn encrypt(&self, data: &mut [u8]) -> Result<(), ModeError> {
+        if !data.len().is_multiple_of(B::SIZE) {
+            return Err(ModeError::InvalidDataLength);
+        }
+
+        let mut blocks = [zero_block::<B>(); BATCH];
+        for chunk in data.chunks_mut(B::SIZE * BATCH) {
+            let blocks = &mut blocks[..chunk.len() / B::SIZE];
+            for (block, bytes) in blocks.iter_mut().zip(chunk.chunks(B::SIZE)) {
+                *block = B::from_be_slice(bytes);
+            }
+
+            self.cipher.encrypt_blocks(blocks)?;
+
+            for (block, bytes) in blocks.iter().zip(chunk.chunks_mut(B::SIZE)) {
+                bytes.copy_from_slice(block.to_block_bytes().as_ref());
+            }
+        }
+        Ok(())
+    }
+
+    /// Decrypts `data` in place
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - ciphertext, its length must be a multiple of the block size
+    pub fn decrypt(&self, data: &mut [u8]) -> Result<(), ModeError> {
+        if !data.len().is_multiple_of(B::SIZE) {
+            return Err(ModeError::InvalidDataLength);
+        }
+
+        let mut blocks = [zero_block::<B>(); BATCH];
+        for chunk in data.chunks_mut(B::SIZE * BATCH) {
+            let blocks = &mut blocks[..chunk.len() / B::SIZE];
+            for (block, bytes) in blocks.iter_mut().zip(chunk.chunks(B::SIZE)) {
+                *block = B::from_be_slice(bytes);
+            }
+
+            self.cipher.decrypt_blocks(blocks)?;
+
+            for (block, bytes) in blocks.iter().zip(chunk.chunks_mut(B::SIZE)) {
+                bytes.copy_from_slice(block.to_block_bytes().as_ref());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cipher::kuznyechik::kuznyechik::KuznyechikBuilder;
+    use crate::core::cipher::magma::magma::MagmaBuilder;
+    use crate::test_purpose::*;
+
+    #[test]
+    fn encrypt_gost_r_34_13() {
+        let ecb = Ecb::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut data = plaintext_bytes();
+
+        ecb.encrypt(&mut data).unwrap();
+        assert_eq!(data, blocks_to_bytes(&ECB_CIPHERTEXT));
+    }
+
+    #[test]
+    fn decrypt_gost_r_34_13() {
+        let ecb = Ecb::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut data = blocks_to_bytes(&ECB_CIPHERTEXT);
+
+        ecb.decrypt(&mut data).unwrap();
+        assert_eq!(data, plaintext_bytes());
+    }
+
+    #[test]
+    fn partial_block_is_rejected() {
+        let ecb = Ecb::new(MagmaBuilder::default().build());
+        let mut data = [0u8; 12];
+
+        assert!(matches!(ecb.encrypt(&mut data), Err(ModeError::InvalidDataLength)));
+    }
+
+    #[test]
+    fn kuznyechik_gost_r_34_13() {
+        let ecb = Ecb::new(KuznyechikBuilder::new().set_key(KUZNYECHIK_KEY).build());
+        let mut data = kuznyechik_blocks_to_bytes(&KUZNYECHIK_MODES_PLAINTEXT);
+
+        ecb.encrypt(&mut data).unwrap();
+        assert_eq!(data, kuznyechik_blocks_to_bytes(&KUZNYECHIK_ECB_CIPHERTEXT));
+        ecb.decrypt(&mut data).unwrap();
+        assert_eq!(data, kuznyechik_blocks_to_bytes(&KUZNYECHIK_MODES_PLAINTEXT));
+    }
+
+    #[test]
+    fn batches_match_single_blocks() {
+        let magma = MagmaBuilder::default().set_key(CIPHER_KEY).build();
+        let ecb = Ecb::new(&magma);
+        let mut data = [0u8; 8 * 19];
+        data.iter_mut().enumerate().for_each(|(i, b)| *b = i as u8);
+        let plaintext = data;
+
+        ecb.encrypt(&mut data).unwrap();
+        for (chunk, plain) in data.chunks(8).zip(plaintext.chunks(8)) {
+            let expected = magma.encrypt(u64::from_be_slice(plain)).unwrap();
+            assert_eq!(u64::from_be_slice(chunk), expected);
+        }
+
+        ecb.decrypt(&mut data).unwrap();
+        assert_eq!(data, plaintext);
+    }
+}
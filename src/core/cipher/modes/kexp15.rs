@@ -0,0 +1,172 @@
+//! Key export and import KExp15/KImp15, R 1323565.1.017-2018, Section 8
+//!
+//! `KExp15(K, IV) = CTR[K_enc](IV, K || OMAC[K_mac](IV || K))`: the key is
+//! encrypted together with its MAC, so a damaged or forged blob is rejected
+//! on import.
+
+use super::ctr::Ctr;
+use super::mac::Mac;
+use super::*;
+use crate::core::cipher::magma::key::Key;
+use crate::core::cipher::Cipher;
+use crate::core::zeroize::Zeroize;
+
+/// Length of the exported key `K || KEYMAC`
+pub const BLOB_LEN: usize = 32 + 8;
+
+#[derive(Debug)]
+pub enum KeyWrapError {
+    /// `KEYMAC` does not match the imported key
+    IntegrityCheckFailed,
+    CipherError,
+}
+
+impl From<ModeError> for KeyWrapError {
+    fn from(_: ModeError) -> Self {
+        Self::CipherError
+    }
+}
+
+/// `Kexp15` wraps and unwraps 256-bit keys with a 64-bit block cipher
+pub struct Kexp15<C> {
+    mac_cipher: C,
+    enc_cipher: C,
+}
+
+impl<C> Kexp15<C>
+where
+    C: Cipher<Input = u64, Output = u64>,
+{
+    /// Constructs scheme
+    ///
+    /// # Arguments
+    ///
+    /// * `mac_cipher` - cipher keyed with `K_Exp_MAC`
+    /// * `enc_cipher` - cipher keyed with `K_Exp_ENC`, must differ from `K_Exp_MAC`
+    pub fn new(mac_cipher: C, enc_cipher: C) -> Self {
+        Self { mac_cipher, enc_cipher }
+    }
+
+    /// Returns `KEYMAC = OMAC(IV || K)`
+    fn key_mac(&self, key: &[u8; 32], iv: u32) -> Result<[u8; 8], ModeError> {
+        let mut data = [0u8; 4 + 32];
+        data[..4].copy_from_slice(&iv.to_be_bytes());
+        data[4..].copy_from_slice(key);
+
+        let mut tag = [0u8; 8];
+        let result = Mac::new(&self.mac_cipher).sign(&data, &mut tag);
+        data.zeroize();
+        result.map(|_| tag)
+    }
+
+    /// Returns wrapped `key`
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - key to export
+    /// * `iv` - initializing value, must be unique for every export under the same keys
+    pub fn export(&self, key: impl Into<Key>, iv: u32) -> Result<[u8; BLOB_LEN], KeyWrapError> {
+        let mut key = key.into().to_bytes();
+
+        let mut blob = [0u8; BLOB_LEN];
+        blob[..32].copy_from_slice(&key);
+        let result = self.key_mac(&key, iv).and_then(|key_mac| {
+            blob[32..].copy_from_slice(&key_mac);
+            Ctr::new(&self.enc_cipher).encrypt(iv, &mut blob)
+        });
+        key.zeroize();
+
+        match result {
+            Ok(()) => Ok(blob),
+            Err(error) => {
+                blob.zeroize();
+                Err(error.into())
+            }
+        }
+    }
+
+    /// Returns key unwrapped from `blob`
+    ///
+    /// # Arguments
+    ///
+    /// * `blob` - output of `export`
+    /// * `iv` - initializing value used on export
+    pub fn import(&self, blob: &[u8; BLOB_LEN], iv: u32) -> Result<Key, KeyWrapError> {
+        let mut data = *blob;
+        let result = Ctr::new(&self.enc_cipher).decrypt(iv, &mut data);
+
+        let mut key = [0u8; 32];
+        key.copy_from_slice(&data[..32]);
+        let result = result.and_then(|_| self.key_mac(&key, iv));
+        let valid = matches!(&result, Ok(key_mac) if constant_time_eq(key_mac, &data[32..]));
+        data.zeroize();
+
+        // Ключ копируется в результат, локальный буфер стирается в любом случае
+        let imported = match result {
+            Ok(_) if valid => Ok(Key::U8(key)),
+            Ok(_) => Err(KeyWrapError::IntegrityCheckFailed),
+            Err(error) => Err(error.into()),
+        };
+        key.zeroize();
+        imported
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cipher::magma::magma::{Magma, MagmaBuilder};
+    use crate::test_purpose::*;
+
+    const IV: u32 = KEXP15_IV;
+
+    fn scheme() -> Kexp15<Magma> {
+        Kexp15::new(
+            MagmaBuilder::new().set_key(KEXP15_K_EXP_MAC).build(),
+            MagmaBuilder::new().set_key(KEXP15_K_EXP_ENC).build(),
+        )
+    }
+
+    #[test]
+    fn export_r1323565_1_017() {
+        let scheme = scheme();
+
+        assert_eq!(scheme.export(KUZNYECHIK_KEY, IV).unwrap(), KEXP15_MAGMA_EXPORT);
+        assert_eq!(scheme.import(&KEXP15_MAGMA_EXPORT, IV).unwrap().to_bytes(), KUZNYECHIK_KEY);
+    }
+
+    #[test]
+    fn export_import_roundtrip() {
+        let scheme = scheme();
+
+        let blob = scheme.export(CIPHER_KEY, IV).unwrap();
+        let key = scheme.import(&blob, IV).unwrap();
+        assert_eq!(key.to_bytes(), CIPHER_KEY_U8_ARRAY);
+
+        // Imported key is ready for use
+        let magma = MagmaBuilder::new().set_key(key).build();
+        assert_eq!(magma.encrypt(PLAINTEXT).unwrap(), CIPHERTEXT);
+    }
+
+    #[test]
+    fn damaged_blob_is_rejected() {
+        let scheme = scheme();
+        let mut blob = scheme.export(CIPHER_KEY, IV).unwrap();
+
+        for byte in 0..BLOB_LEN {
+            blob[byte] ^= 0x80;
+            assert!(matches!(scheme.import(&blob, IV), Err(KeyWrapError::IntegrityCheckFailed)));
+            blob[byte] ^= 0x80;
+        }
+        assert!(matches!(scheme.import(&blob, IV ^ 1), Err(KeyWrapError::IntegrityCheckFailed)));
+    }
+
+    #[test]
+    fn wrong_keys_are_rejected() {
+        let blob = scheme().export(CIPHER_KEY, IV).unwrap();
+        let other = Kexp15::new(MagmaBuilder::default().build(), MagmaBuilder::default().build());
+
+        assert!(matches!(other.import(&blob, IV), Err(KeyWrapError::IntegrityCheckFailed)));
+    }
+}
+
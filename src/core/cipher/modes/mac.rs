@@ -0,0 +1,164 @@
+//! [Message Authentication Code Generation Mode](https://www.tc26.ru/standard/gost/GOST_R_3413-2015.pdf), Section 5.6
+//!
+//! Tag length `s` is configured in whole bytes, from 1 byte up to the block size.
+
+use super::*;
+use crate::core::cipher::Cipher;
+
+/// `Mac` computes and verifies imitovstavka (CMAC)
+pub struct Mac<C> {
+    cipher: C,
+}
+
+impl<C> Mac<C> {
+    pub fn new(cipher: C) -> Self {
+        Self { cipher }
+    }
+}
+
+impl<C, B> Mac<C>
+where
+    B: Block,
+    C: Cipher<Input = B, Output = B>,
+{
+    /// Returns subkeys `(K1, K2)`
+    pub fn subkeys(&self) -> Result<(B, B), ModeError> {
+        let r = self.cipher.encrypt(B::from_block_bytes(B::Bytes::default()))?;
+        let k1 = r.double();
+        Ok((k1, k1.double()))
+    }
+
+    /// Returns full-block MAC of `data`
+    pub fn compute(&self, data: &[u8]) -> Result<B, ModeError> {
+        let (k1, k2) = self.subkeys()?;
+
+        // The last block is processed with a subkey, even if it is empty
+        let last_len = match data.len() % B::SIZE {
+            0 if !data.is_empty() => B::SIZE,
+            rem => rem,
+        };
+        let (head, last) = data.split_at(data.len() - last_len);
+
+        let mut state = B::from_block_bytes(B::Bytes::default());
+        for chunk in head.chunks(B::SIZE) {
+            state = self.cipher.encrypt(state ^ B::from_be_slice(chunk))?;
+        }
+
+        let last = if last_len == B::SIZE {
+            B::from_be_slice(last) ^ k1
+        } else {
+            // Padding procedure 3
+            let mut padded = B::Bytes::default();
+            padded.as_mut()[..last_len].copy_from_slice(last);
+            padded.as_mut()[last_len] = 0x80;
+            B::from_block_bytes(padded) ^ k2
+        };
+
+        Ok(self.cipher.encrypt(state ^ last)?)
+    }
+
+    /// Writes `MSB_s(MAC)` of `data` into `tag`, where `s` is the length of `tag`
+    pub fn sign(&self, data: &[u8], tag: &mut [u8]) -> Result<(), ModeError> {
+        if tag.is_empty() || tag.len() > B::SIZE {
+            return Err(ModeError::InvalidTagLength);
+        }
+
+        let mac = self.compute(data)?.to_block_bytes();
+        tag.copy_from_slice(&mac.as_ref()[..tag.len()]);
+        Ok(())
+    }
+
+    /// Checks that `tag` is the (possibly truncated) MAC of `data`.
+    /// Tags are compared in constant time.
+    pub fn verify(&self, data: &[u8], tag: &[u8]) -> Result<(), ModeError> {
+        if tag.is_empty() || tag.len() > B::SIZE {
+            return Err(ModeError::InvalidTagLength);
+        }
+
+        let mac = self.compute(data)?.to_block_bytes();
+        if constant_time_eq(&mac.as_ref()[..tag.len()], tag) {
+            Ok(())
+        } else {
+            Err(ModeError::TagMismatch)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cipher::kuznyechik::kuznyechik::KuznyechikBuilder;
+    use crate::core::cipher::magma::magma::MagmaBuilder;
+    use crate::test_purpose::*;
+
+    #[test]
+    fn subkeys_gost_r_34_13() {
+        let mac = Mac::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        assert_eq!(mac.subkeys().unwrap(), (MAC_K1, MAC_K2));
+    }
+
+    #[test]
+    fn sign_gost_r_34_13() {
+        let mac = Mac::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut tag = [0u8; 4];
+
+        mac.sign(&plaintext_bytes(), &mut tag).unwrap();
+        assert_eq!(tag, MAC_32);
+    }
+
+    #[test]
+    fn verify_gost_r_34_13() {
+        let mac = Mac::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        assert!(mac.verify(&plaintext_bytes(), &MAC_32).is_ok());
+    }
+
+    #[test]
+    fn full_and_truncated_tags_agree() {
+        let mac = Mac::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut tag = [0u8; 8];
+
+        mac.sign(&plaintext_bytes(), &mut tag).unwrap();
+        assert_eq!(tag[..4], MAC_32);
+        assert!(mac.verify(&plaintext_bytes(), &tag).is_ok());
+    }
+
+    #[test]
+    fn tampered_data_is_rejected() {
+        let mac = Mac::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut data = plaintext_bytes();
+
+        for byte in 0..data.len() {
+            data[byte] ^= 0x01;
+            assert!(matches!(mac.verify(&data, &MAC_32), Err(ModeError::TagMismatch)));
+            data[byte] ^= 0x01;
+        }
+    }
+
+    #[test]
+    fn incomplete_last_block() {
+        let mac = Mac::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut tag = [0u8; 8];
+
+        mac.sign(&plaintext_bytes()[..13], &mut tag).unwrap();
+        assert!(mac.verify(&plaintext_bytes()[..13], &tag).is_ok());
+        assert!(mac.verify(&plaintext_bytes()[..14], &tag).is_err());
+        assert!(mac.sign(&[], &mut tag).is_ok());
+    }
+
+    #[test]
+    fn invalid_tag_length() {
+        let mac = Mac::new(MagmaBuilder::default().build());
+
+        assert!(matches!(mac.sign(&[0u8; 8], &mut [0u8; 9]), Err(ModeError::InvalidTagLength)));
+        assert!(matches!(mac.verify(&[0u8; 8], &[]), Err(ModeError::InvalidTagLength)));
+    }
+
+    #[test]
+    fn kuznyechik_gost_r_34_13() {
+        let mac = Mac::new(KuznyechikBuilder::new().set_key(KUZNYECHIK_KEY).build());
+        let mut tag = [0u8; 8];
+
+        mac.sign(&kuznyechik_blocks_to_bytes(&KUZNYECHIK_MODES_PLAINTEXT), &mut tag).unwrap();
+        assert_eq!(tag, KUZNYECHIK_MAC_64);
+    }
+}
@@ -0,0 +1,207 @@
+//! [OMAC with ACPKM-Master re-keying](https://datatracker.ietf.org/doc/html/rfc8645.html#section-6.4), R 1323565.1.017-2018
+//!
+//! Section keys `K^i` and MAC subkeys `K^i_1` are taken from the key stream
+//! `ACPKM-Master(T*, K) = CTR-ACPKM(T*, K, 1^(n/2), 0...0)`, every section of
+//! `N` bytes of the message is processed under its own key.
+
+use super::acpkm::{section_blocks, AcpkmGamma, KEY_SIZE};
+use super::*;
+use crate::core::cipher::{Cipher, Rekey};
+use crate::core::zeroize::Zeroize;
+
+/// Largest number of blocks in `K^i || K^i_1`
+const MAX_MATERIAL_BLOCKS: usize = KEY_SIZE / 8 + 1;
+
+/// `MacAcpkm` computes and verifies OMAC-ACPKM
+pub struct MacAcpkm<C> {
+    cipher: C,
+    section_blocks: usize,
+    master_section_blocks: usize,
+}
+
+impl<C, B> MacAcpkm<C>
+where
+    B: Block,
+    C: Cipher<Input = B, Output = B> + Rekey + Clone,
+{
+    /// Constructs mode
+    ///
+    /// # Arguments
+    ///
+    /// * `cipher` - cipher keyed with the master key `K`
+    /// * `section_size` - message section size `N` in bytes, a positive multiple of the block size
+    /// * `master_section_size` - section size `T*` of the key stream in bytes, a positive multiple of the block size
+    pub fn new(cipher: C, section_size: usize, master_section_size: usize) -> Result<Self, ModeError> {
+        Ok(Self {
+            cipher,
+            section_blocks: section_blocks::<B>(section_size)?,
+            master_section_blocks: section_blocks::<B>(master_section_size)?,
+        })
+    }
+
+    /// Returns cipher keyed with the next `K^i` and subkey `K^i_1`
+    fn next_section(&self, master: &mut AcpkmGamma<C, B>) -> Result<(C, B), ModeError> {
+        let key_blocks = KEY_SIZE / B::SIZE;
+        let mut material = [zero_block::<B>(); MAX_MATERIAL_BLOCKS];
+        master.fill(&mut material[..=key_blocks])?;
+
+        let mut key = [0u8; KEY_SIZE];
+        for (chunk, block) in key.chunks_mut(B::SIZE).zip(material.iter()) {
+            chunk.copy_from_slice(block.to_block_bytes().as_ref());
+        }
+
+        let mut cipher = self.cipher.clone();
+        cipher.rekey(key);
+        key.zeroize();
+        Ok((cipher, material[key_blocks]))
+    }
+
+    /// Returns full-block MAC of `data`
+    pub fn compute(&self, data: &[u8]) -> Result<B, ModeError> {
+        let mut master = AcpkmGamma::new(self.cipher.clone(), B::from_halves(u64::MAX, 0), self.master_section_blocks);
+        let (mut cipher, mut k_1) = self.next_section(&mut master)?;
+
+        // The last block is processed with a subkey, even if it is empty
+        let last_len = match data.len() % B::SIZE {
+            0 if !data.is_empty() => B::SIZE,
+            rem => rem,
+        };
+        let (head, last) = data.split_at(data.len() - last_len);
+
+        let mut state = zero_block::<B>();
+        for (i, chunk) in head.chunks(B::SIZE).enumerate() {
+            if i != 0 && i % self.section_blocks == 0 {
+                (cipher, k_1) = self.next_section(&mut master)?;
+            }
+            state = cipher.encrypt(state ^ B::from_be_slice(chunk))?;
+        }
+
+        let last_index = head.len() / B::SIZE;
+        if last_index != 0 && last_index % self.section_blocks == 0 {
+            (cipher, k_1) = self.next_section(&mut master)?;
+        }
+
+        let last = if last_len == B::SIZE {
+            B::from_be_slice(last) ^ k_1
+        } else {
+            // Padding procedure 3, the subkey is doubled as `K_2` of OMAC
+            let mut padded = B::Bytes::default();
+            padded.as_mut()[..last_len].copy_from_slice(last);
+            padded.as_mut()[last_len] = 0x80;
+            B::from_block_bytes(padded) ^ k_1.double()
+        };
+
+        Ok(cipher.encrypt(state ^ last)?)
+    }
+
+    /// Writes `MSB_s(MAC)` of `data` into `tag`, where `s` is the length of `tag`
+    pub fn sign(&self, data: &[u8], tag: &mut [u8]) -> Result<(), ModeError> {
+        if tag.is_empty() || tag.len() > B::SIZE {
+            return Err(ModeError::InvalidTagLength);
+        }
+
+        let mac = self.compute(data)?.to_block_bytes();
+        tag.copy_from_slice(&mac.as_ref()[..tag.len()]);
+        Ok(())
+    }
+
+    /// Checks that `tag` is the (possibly truncated) MAC of `data`.
+    /// Tags are compared in constant time.
+    pub fn verify(&self, data: &[u8], tag: &[u8]) -> Result<(), ModeError> {
+        if tag.is_empty() || tag.len() > B::SIZE {
+            return Err(ModeError::InvalidTagLength);
+        }
+
+        let mac = self.compute(data)?.to_block_bytes();
+        if constant_time_eq(&mac.as_ref()[..tag.len()], tag) {
+            Ok(())
+        } else {
+            Err(ModeError::TagMismatch)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cipher::kuznyechik::kuznyechik::KuznyechikBuilder;
+    use crate::core::cipher::magma::magma::{Magma, MagmaBuilder};
+    use crate::test_purpose::*;
+
+    fn mac() -> MacAcpkm<Magma> {
+        MacAcpkm::new(MagmaBuilder::new().set_key(KUZNYECHIK_KEY).build(), 16, 32).unwrap()
+    }
+
+    #[test]
+    fn magma_rfc8645() {
+        let mac = MacAcpkm::new(MagmaBuilder::new().set_key(KUZNYECHIK_KEY).build(), 16, 80).unwrap();
+
+        assert_eq!(mac.compute(&ACPKM_PLAINTEXT[..12]).unwrap(), MAGMA_OMAC_ACPKM_SHORT);
+        assert_eq!(mac.compute(&ACPKM_PLAINTEXT[..40]).unwrap(), MAGMA_OMAC_ACPKM_LONG);
+    }
+
+    #[test]
+    fn kuznyechik_rfc8645() {
+        let mac = MacAcpkm::new(KuznyechikBuilder::new().set_key(KUZNYECHIK_KEY).build(), 32, 96).unwrap();
+
+        assert_eq!(mac.compute(&ACPKM_PLAINTEXT[..24]).unwrap(), KUZNYECHIK_OMAC_ACPKM_SHORT);
+        assert_eq!(mac.compute(&ACPKM_PLAINTEXT[..80]).unwrap(), KUZNYECHIK_OMAC_ACPKM_LONG);
+    }
+
+    #[test]
+    fn sign_and_verify_across_sections() {
+        let mac = mac();
+        let data = plaintext_bytes();
+
+        for len in 0..=data.len() {
+            let mut tag = [0u8; 8];
+            mac.sign(&data[..len], &mut tag).unwrap();
+            assert!(mac.verify(&data[..len], &tag).is_ok());
+            assert!(mac.verify(&data[..len], &tag[..4]).is_ok());
+        }
+    }
+
+    #[test]
+    fn tampered_data_is_rejected() {
+        let mac = mac();
+        let mut data = plaintext_bytes();
+        let mut tag = [0u8; 8];
+        mac.sign(&data, &mut tag).unwrap();
+
+        for byte in 0..data.len() {
+            data[byte] ^= 0x01;
+            assert!(matches!(mac.verify(&data, &tag), Err(ModeError::TagMismatch)));
+            data[byte] ^= 0x01;
+        }
+    }
+
+    #[test]
+    fn section_size_changes_tag() {
+        let short = mac();
+        let long = MacAcpkm::new(MagmaBuilder::new().set_key(KUZNYECHIK_KEY).build(), 32, 32).unwrap();
+
+        assert_ne!(short.compute(&plaintext_bytes()).unwrap(), long.compute(&plaintext_bytes()).unwrap());
+        // Both agree while the message fits into one section
+        assert_eq!(short.compute(&plaintext_bytes()[..16]).unwrap(), long.compute(&plaintext_bytes()[..16]).unwrap());
+    }
+
+    #[test]
+    fn kuznyechik_sign_and_verify() {
+        let kuznyechik = KuznyechikBuilder::new().set_key(KUZNYECHIK_KEY).build();
+        let mac = MacAcpkm::new(kuznyechik, 32, 48).unwrap();
+        let data = kuznyechik_blocks_to_bytes(&KUZNYECHIK_MODES_PLAINTEXT);
+        let mut tag = [0u8; 16];
+
+        mac.sign(&data, &mut tag).unwrap();
+        assert!(mac.verify(&data, &tag).is_ok());
+        assert!(mac.verify(&data[..63], &tag).is_err());
+    }
+
+    #[test]
+    fn invalid_sizes() {
+        let magma = MagmaBuilder::default().build();
+        assert!(matches!(MacAcpkm::new(magma.clone(), 16, 0), Err(ModeError::InvalidSectionSize)));
+        assert!(matches!(MacAcpkm::new(magma, 9, 16), Err(ModeError::InvalidSectionSize)));
+        assert!(matches!(mac().sign(&[0u8; 8], &mut [0u8; 9]), Err(ModeError::InvalidTagLength)));
+    }
+}
@@ -0,0 +1,206 @@
+//! [Multilinear Galois Mode](https://datatracker.ietf.org/doc/html/rfc9058.html)
+//!
+//! Authenticated encryption with associated data. Tag length `S` is
+//! configured in whole bytes, from 4 bytes (32 bits) up to the block size.
+
+use super::*;
+use crate::core::cipher::Cipher;
+
+/// Minimal tag length in bytes, [Section 4](https://datatracker.ietf.org/doc/html/rfc9058.html#section-4)
+pub const MIN_TAG_LENGTH: usize = 4;
+
+/// `Mgm` encrypts and authenticates a message, and authenticates
+/// associated data (e.g. a frame header) which is sent in clear
+pub struct Mgm<C> {
+    cipher: C,
+}
+
+impl<C> Mgm<C> {
+    pub fn new(cipher: C) -> Self {
+        Self { cipher }
+    }
+}
+
+impl<C, B> Mgm<C>
+where
+    B: Block,
+    C: Cipher<Input = B, Output = B>,
+{
+    /// Encrypts `data` in place and writes `MSB_S(T)` into `tag`
+    ///
+    /// # Arguments
+    ///
+    /// * `nonce` - `0 || ICN`, must be unique for every message under the key
+    /// * `associated_data` - authenticated but not encrypted data
+    /// * `data` - plaintext of any length
+    /// * `tag` - output, its length is `S`
+    pub fn encrypt(&self, nonce: B, associated_data: &[u8], data: &mut [u8], tag: &mut [u8]) -> Result<(), ModeError> {
+        self.check_arguments(nonce, associated_data, data, tag.len())?;
+
+        self.apply_gamma(nonce, data)?;
+        let full_tag = self.compute_tag(nonce, associated_data, data)?.to_block_bytes();
+        tag.copy_from_slice(&full_tag.as_ref()[..tag.len()]);
+        Ok(())
+    }
+
+    /// Verifies `tag` and only then decrypts `data` in place.
+    /// Tags are compared in constant time; on mismatch `data` is left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `nonce` - `0 || ICN` used for encryption
+    /// * `associated_data` - authenticated but not encrypted data
+    /// * `data` - ciphertext of any length
+    /// * `tag` - received `MSB_S(T)`
+    pub fn decrypt(&self, nonce: B, associated_data: &[u8], data: &mut [u8], tag: &[u8]) -> Result<(), ModeError> {
+        self.check_arguments(nonce, associated_data, data, tag.len())?;
+
+        let full_tag = self.compute_tag(nonce, associated_data, data)?.to_block_bytes();
+        if !constant_time_eq(&full_tag.as_ref()[..tag.len()], tag) {
+            return Err(ModeError::TagMismatch);
+        }
+        self.apply_gamma(nonce, data)
+    }
+
+    fn check_arguments(&self, nonce: B, associated_data: &[u8], data: &[u8], tag_len: usize) -> Result<(), ModeError> {
+        if nonce.msb() {
+            return Err(ModeError::InvalidNonce);
+        }
+        if !(MIN_TAG_LENGTH..=B::SIZE).contains(&tag_len) {
+            return Err(ModeError::InvalidTagLength);
+        }
+
+        // Bit lengths of both strings must fit into `n/2` bits
+        let max_len = 1u64 << (B::SIZE * 4 - 3);
+        if associated_data.len() as u64 >= max_len || data.len() as u64 >= max_len {
+            return Err(ModeError::InvalidDataLength);
+        }
+        Ok(())
+    }
+
+    /// `C_i = P_i xor MSB_u(E_K(Y_i))`, where `Y_1 = E_K(0 || ICN)`
+    fn apply_gamma(&self, nonce: B, data: &mut [u8]) -> Result<(), ModeError> {
+        let mut y = self.cipher.encrypt(nonce)?;
+        for chunk in data.chunks_mut(B::SIZE) {
+            xor_in_place(chunk, self.cipher.encrypt(y)?);
+            y = y.increment_right();
+        }
+        Ok(())
+    }
+
+    /// `T = E_K(sum(H_i ⊗ A_i) xor sum(H_j ⊗ C_j) xor H ⊗ (len(A) || len(C)))`,
+    /// where `Z_1 = E_K(1 || ICN)` and `H_i = E_K(Z_i)`
+    fn compute_tag(&self, nonce: B, associated_data: &[u8], ciphertext: &[u8]) -> Result<B, ModeError> {
+        let mut z = self.cipher.encrypt(nonce.set_msb())?;
+        let mut sum = B::from_block_bytes(B::Bytes::default());
+
+        for chunk in associated_data.chunks(B::SIZE).chain(ciphertext.chunks(B::SIZE)) {
+            let mut block = B::Bytes::default();
+            block.as_mut()[..chunk.len()].copy_from_slice(chunk);
+
+            sum = sum ^ self.cipher.encrypt(z)?.gf_mul(B::from_block_bytes(block));
+            z = z.increment_left();
+        }
+
+        let lengths = B::from_halves(associated_data.len() as u64 * 8, ciphertext.len() as u64 * 8);
+        sum = sum ^ self.cipher.encrypt(z)?.gf_mul(lengths);
+
+        Ok(self.cipher.encrypt(sum)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cipher::kuznyechik::kuznyechik::KuznyechikBuilder;
+    use crate::core::cipher::magma::magma::MagmaBuilder;
+    use crate::test_purpose::*;
+
+    #[test]
+    fn encrypt_rfc9058() {
+        let mgm = Mgm::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut data = MGM_PLAINTEXT;
+        let mut tag = [0u8; 8];
+
+        mgm.encrypt(MGM_NONCE, &MGM_ASSOCIATED_DATA, &mut data, &mut tag).unwrap();
+        assert_eq!(data, MGM_CIPHERTEXT);
+        assert_eq!(tag, MGM_TAG);
+    }
+
+    #[test]
+    fn decrypt_rfc9058() {
+        let mgm = Mgm::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut data = MGM_CIPHERTEXT;
+
+        mgm.decrypt(MGM_NONCE, &MGM_ASSOCIATED_DATA, &mut data, &MGM_TAG).unwrap();
+        assert_eq!(data, MGM_PLAINTEXT);
+    }
+
+    #[test]
+    fn truncated_tag() {
+        let mgm = Mgm::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut data = MGM_PLAINTEXT;
+        let mut tag = [0u8; 4];
+
+        mgm.encrypt(MGM_NONCE, &MGM_ASSOCIATED_DATA, &mut data, &mut tag).unwrap();
+        assert_eq!(tag, MGM_TAG[..4]);
+        mgm.decrypt(MGM_NONCE, &MGM_ASSOCIATED_DATA, &mut data, &tag).unwrap();
+        assert_eq!(data, MGM_PLAINTEXT);
+    }
+
+    #[test]
+    fn tampering_is_detected() {
+        let mgm = Mgm::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+
+        let mut data = MGM_CIPHERTEXT;
+        data[10] ^= 0x04;
+        assert!(matches!(
+            mgm.decrypt(MGM_NONCE, &MGM_ASSOCIATED_DATA, &mut data, &MGM_TAG),
+            Err(ModeError::TagMismatch)
+        ));
+        // Ciphertext is not decrypted when the tag is wrong
+        assert_eq!(data[11..], MGM_CIPHERTEXT[11..]);
+
+        let mut header = MGM_ASSOCIATED_DATA;
+        header[40] ^= 0x80;
+        let mut data = MGM_CIPHERTEXT;
+        assert!(matches!(
+            mgm.decrypt(MGM_NONCE, &header, &mut data, &MGM_TAG),
+            Err(ModeError::TagMismatch)
+        ));
+
+        let mut data = MGM_CIPHERTEXT;
+        assert!(matches!(
+            mgm.decrypt(MGM_NONCE ^ 1, &MGM_ASSOCIATED_DATA, &mut data, &MGM_TAG),
+            Err(ModeError::TagMismatch)
+        ));
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        let mgm = Mgm::new(MagmaBuilder::default().build());
+        let mut data = [0u8; 8];
+
+        assert!(matches!(
+            mgm.encrypt(1 << 63, &[], &mut data, &mut [0u8; 8]),
+            Err(ModeError::InvalidNonce)
+        ));
+        assert!(matches!(
+            mgm.encrypt(0, &[], &mut data, &mut [0u8; 3]),
+            Err(ModeError::InvalidTagLength)
+        ));
+    }
+
+    #[test]
+    fn kuznyechik_rfc9058() {
+        let mgm = Mgm::new(KuznyechikBuilder::new().set_key(KUZNYECHIK_KEY).build());
+        let mut data = KUZNYECHIK_MGM_PLAINTEXT;
+        let mut tag = [0u8; 16];
+
+        mgm.encrypt(KUZNYECHIK_MGM_NONCE, &KUZNYECHIK_MGM_ASSOCIATED_DATA, &mut data, &mut tag).unwrap();
+        assert_eq!(data, KUZNYECHIK_MGM_CIPHERTEXT);
+        assert_eq!(tag, KUZNYECHIK_MGM_TAG);
+        mgm.decrypt(KUZNYECHIK_MGM_NONCE, &KUZNYECHIK_MGM_ASSOCIATED_DATA, &mut data, &tag).unwrap();
+        assert_eq!(data, KUZNYECHIK_MGM_PLAINTEXT);
+    }
+}
@@ -0,0 +1,271 @@
+//! [Block cipher modes, GOST R 34.13-2015](https://www.tc26.ru/standard/gost/GOST_R_3413-2015.pdf)
+//!
+//! Every mode wraps a `Cipher` whose `Input` and `Output` are the same
+//! [`Block`] type and processes byte slices in place. Blocks are read from
+//! and written to bytes in big-endian order, as in the standard.
+
+use core::ops::BitXor;
+
+use crate::core::cipher::{kuznyechik, magma::utils};
+use crate::core::cipher::CipherError;
+
+pub mod acpkm;
+pub mod cbc;
+pub mod cfb;
+pub mod ctr;
+pub mod ctr_acpkm;
+pub mod ecb;
+pub mod kexp15;
+pub mod mac;
+pub mod mac_acpkm;
+pub mod mgm;
+pub mod ofb;
+pub mod padding;
+
+#[derive(Debug)]
+pub enum ModeError {
+    InvalidDataLength,
+    InvalidTagLength,
+    InvalidNonce,
+    InvalidSectionSize,
+    InvalidPadding,
+    TagMismatch,
+    EncryptError,
+    DecryptError,
+}
+
+impl From<CipherError> for ModeError {
+    fn from(value: CipherError) -> Self {
+        match value {
+            CipherError::EncryptError => Self::EncryptError,
+            CipherError::DecryptError => Self::DecryptError,
+        }
+    }
+}
+
+/// Cipher block which can be used by modes of operation
+pub trait Block: Copy + BitXor<Output = Self> {
+    /// Byte representation of the block
+    type Bytes: AsRef<[u8]> + AsMut<[u8]> + Default;
+    /// Half of the block, used as `CTR` initializing value
+    type Half;
+
+    /// Block size in bytes
+    const SIZE: usize;
+
+    fn from_block_bytes(bytes: Self::Bytes) -> Self;
+    fn to_block_bytes(self) -> Self::Bytes;
+
+    /// Returns `half || 0...0`
+    fn from_half(half: Self::Half) -> Self;
+
+    /// Returns `Add(block, 1)` in `Z_(2^n)`
+    fn increment(self) -> Self;
+
+    /// Returns `left || right`, each part truncated to `n/2` bits
+    fn from_halves(left: u64, right: u64) -> Self;
+
+    /// Returns `MSB_1(block) = 1`
+    fn msb(self) -> bool;
+
+    /// Returns `1 || LSB_(n-1)(block)`
+    fn set_msb(self) -> Self;
+
+    /// Returns `incr_l(block)`: the left half incremented in `Z_(2^(n/2))`
+    fn increment_left(self) -> Self;
+
+    /// Returns `incr_r(block)`: the right half incremented in `Z_(2^(n/2))`
+    fn increment_right(self) -> Self;
+
+    /// Returns `block ⊗ other`, product in `GF(2^n)`
+    fn gf_mul(self, other: Self) -> Self;
+
+    /// Returns `block << 1` if `MSB_1(block) = 0`, otherwise `(block << 1) ^ B_n`,
+    /// as used for `MAC` subkey generation
+    fn double(self) -> Self;
+
+    /// Returns block read from big-endian `bytes`
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - slice of exactly `Self::SIZE` bytes
+    #[inline]
+    fn from_be_slice(bytes: &[u8]) -> Self {
+        let mut block = Self::Bytes::default();
+        block.as_mut().copy_from_slice(bytes);
+        Self::from_block_bytes(block)
+    }
+}
+
+impl Block for u64 {
+    type Bytes = [u8; 8];
+    type Half = u32;
+
+    const SIZE: usize = 8;
+
+    #[inline]
+    fn from_block_bytes(bytes: Self::Bytes) -> Self {
+        u64::from_be_bytes(bytes)
+    }
+
+    #[inline]
+    fn to_block_bytes(self) -> Self::Bytes {
+        self.to_be_bytes()
+    }
+
+    #[inline]
+    fn from_half(half: Self::Half) -> Self {
+        (half as u64) << 32
+    }
+
+    #[inline]
+    fn increment(self) -> Self {
+        self.wrapping_add(1)
+    }
+
+    #[inline]
+    fn from_halves(left: u64, right: u64) -> Self {
+        (left << 32) | (right & 0xFFFF_FFFF)
+    }
+
+    #[inline]
+    fn msb(self) -> bool {
+        self >> 63 == 1
+    }
+
+    #[inline]
+    fn set_msb(self) -> Self {
+        self | (1 << 63)
+    }
+
+    #[inline]
+    fn increment_left(self) -> Self {
+        let (left, right) = utils::u64_split(self);
+        utils::u32_join(left.wrapping_add(1), right)
+    }
+
+    #[inline]
+    fn increment_right(self) -> Self {
+        let (left, right) = utils::u64_split(self);
+        utils::u32_join(left, right.wrapping_add(1))
+    }
+
+    /// `GF(2^64)` is defined by `x^64 + x^4 + x^3 + x + 1`
+    fn gf_mul(self, other: Self) -> Self {
+        let mut a = self;
+        let mut res = 0;
+        for i in 0..64 {
+            // Both branches are taken on every bit, so timing does not depend on operands
+            res ^= a & ((other >> i) & 1).wrapping_neg();
+            a = a.double();
+        }
+        res
+    }
+
+    #[inline]
+    fn double(self) -> Self {
+        // B_64 = 0^59 || 11011
+        (self << 1) ^ ((self >> 63) * 0x1b)
+    }
+}
+
+impl Block for u128 {
+    type Bytes = [u8; 16];
+    type Half = u64;
+
+    const SIZE: usize = 16;
+
+    #[inline]
+    fn from_block_bytes(bytes: Self::Bytes) -> Self {
+        u128::from_be_bytes(bytes)
+    }
+
+    #[inline]
+    fn to_block_bytes(self) -> Self::Bytes {
+        self.to_be_bytes()
+    }
+
+    #[inline]
+    fn from_half(half: Self::Half) -> Self {
+        (half as u128) << 64
+    }
+
+    #[inline]
+    fn increment(self) -> Self {
+        self.wrapping_add(1)
+    }
+
+    #[inline]
+    fn from_halves(left: u64, right: u64) -> Self {
+        kuznyechik::utils::u64_join(left, right)
+    }
+
+    #[inline]
+    fn msb(self) -> bool {
+        self >> 127 == 1
+    }
+
+    #[inline]
+    fn set_msb(self) -> Self {
+        self | (1 << 127)
+    }
+
+    #[inline]
+    fn increment_left(self) -> Self {
+        let (left, right) = kuznyechik::utils::u128_split(self);
+        kuznyechik::utils::u64_join(left.wrapping_add(1), right)
+    }
+
+    #[inline]
+    fn increment_right(self) -> Self {
+        let (left, right) = kuznyechik::utils::u128_split(self);
+        kuznyechik::utils::u64_join(left, right.wrapping_add(1))
+    }
+
+    /// `GF(2^128)` is defined by `x^128 + x^7 + x^2 + x + 1`
+    fn gf_mul(self, other: Self) -> Self {
+        let mut a = self;
+        let mut res = 0;
+        for i in 0..128 {
+            res ^= a & ((other >> i) & 1).wrapping_neg();
+            a = a.double();
+        }
+        res
+    }
+
+    #[inline]
+    fn double(self) -> Self {
+        // B_128 = 0^120 || 10000111
+        (self << 1) ^ ((self >> 127) * 0x87)
+    }
+}
+
+/// Number of blocks handed to `Cipher::encrypt_blocks` at once
+pub(crate) const BATCH: usize = 8;
+
+/// Returns the all-zero block
+#[inline]
+pub(crate) fn zero_block<B: Block>() -> B {
+    B::from_block_bytes(B::Bytes::default())
+}
+
+/// XORs `data` with the most significant bytes of `gamma`
+#[inline]
+pub(crate) fn xor_in_place<B: Block>(data: &mut [u8], gamma: B) {
+    let gamma = gamma.to_block_bytes();
+    data.iter_mut()
+        .zip(gamma.as_ref())
+        .for_each(|(d, g)| *d ^= g);
+}
+
+/// Compares `a` and `b` in time independent of their content
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let diff = a.iter()
+        .zip(b.iter())
+        .fold(0u8, |acc, (x, y)| acc | (x ^ y));
+    core::hint::black_box(diff) == 0
+}
@@ -0,0 +1,76 @@
+//! [Output Feedback Mode](https://www.tc26.ru/standard/gost/GOST_R_3413-2015.pdf), Section 5.3
+//!
+//! Implemented with `s = n`: the whole gamma block is used. The last block
+//! of the message may be incomplete.
+
+use super::*;
+use crate::core::cipher::Cipher;
+
+/// `Ofb` with a shift register of `Z` blocks
+pub struct Ofb<C> {
+    cipher: C,
+}
+
+impl<C> Ofb<C> {
+    pub fn new(cipher: C) -> Self {
+        Self { cipher }
+    }
+}
+
+impl<C, B> Ofb<C>
+where
+    B: Block,
+    C: Cipher<Input = B, Output = B>,
+{
+    /// XORs `data` in place with the gamma produced from `iv`
+    ///
+    /// # Arguments
+    ///
+    /// * `iv` - initializing value of `Z` blocks, the first block is the most significant
+    /// * `data` - plaintext or ciphertext of any length
+    pub fn apply_gamma<const Z: usize>(&self, iv: [B; Z], data: &mut [u8]) -> Result<(), ModeError> {
+        // The register `R` is kept as a ring: `register[i % Z]` is `MSB_n(R)` for block `i`
+        let mut register = iv;
+        for (i, chunk) in data.chunks_mut(B::SIZE).enumerate() {
+            let gamma = self.cipher.encrypt(register[i % Z])?;
+            register[i % Z] = gamma;
+            xor_in_place(chunk, gamma);
+        }
+        Ok(())
+    }
+
+    /// Encrypts `data` in place
+    pub fn encrypt<const Z: usize>(&self, iv: [B; Z], data: &mut [u8]) -> Result<(), ModeError> {
+        self.apply_gamma(iv, data)
+    }
+
+    /// Decrypts `data` in place
+    pub fn decrypt<const Z: usize>(&self, iv: [B; Z], data: &mut [u8]) -> Result<(), ModeError> {
+        self.apply_gamma(iv, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cipher::magma::magma::MagmaBuilder;
+    use crate::test_purpose::*;
+
+    #[test]
+    fn encrypt_gost_r_34_13() {
+        let ofb = Ofb::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut data = plaintext_bytes();
+
+        ofb.encrypt(OFB_IV, &mut data).unwrap();
+        assert_eq!(data, blocks_to_bytes(&OFB_CIPHERTEXT));
+    }
+
+    #[test]
+    fn decrypt_gost_r_34_13() {
+        let ofb = Ofb::new(MagmaBuilder::default().set_key(CIPHER_KEY).build());
+        let mut data = blocks_to_bytes(&OFB_CIPHERTEXT);
+
+        ofb.decrypt(OFB_IV, &mut data).unwrap();
+        assert_eq!(data, plaintext_bytes());
+    }
+}
@@ -0,0 +1,168 @@
+//! Padding procedures, [GOST R 34.13-2015](https://www.tc26.ru/standard/gost/GOST_R_3413-2015.pdf), Section 4.1
+//!
+//! Procedures 1 and 3 do not mark the message length, the standard expects
+//! it to be known to the receiver. Their `unpad` strips what looks like
+//! padding, so a message ending with such bytes can not be recovered.
+//! Unpadding runs in time independent of the padding content and reports
+//! every failure as `ModeError::InvalidPadding`.
+
+use super::*;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Padding {
+    /// Procedure 1: `P || 0...0`, nothing is added to whole blocks
+    Zero,
+    /// Procedure 2: `P || 1 || 0...0`, a whole block is added to whole blocks
+    #[default]
+    Bit,
+    /// Procedure 3: `P || 1 || 0...0`, nothing is added to whole blocks
+    ConditionalBit,
+}
+
+/// Returns `0xFF` if `a == b`, otherwise `0x00`
+#[inline]
+fn eq_mask(a: u8, b: u8) -> u8 {
+    (((a ^ b) as u16).wrapping_sub(1) >> 8) as u8
+}
+
+/// Returns `a` if `mask` is `0xFF`, `b` if it is `0x00`
+#[inline]
+fn select(mask: u8, a: usize, b: usize) -> usize {
+    let mask = (mask as i8) as isize as usize;
+    (a & mask) | (b & !mask)
+}
+
+impl Padding {
+    /// Returns length of a padded message of `len` bytes
+    pub fn padded_len<B: Block>(self, len: usize) -> usize {
+        match self {
+            Self::Zero | Self::ConditionalBit => len.div_ceil(B::SIZE) * B::SIZE,
+            Self::Bit => (len / B::SIZE + 1) * B::SIZE,
+        }
+    }
+
+    /// Pads message `buf[..len]` in place, returns padded length
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - buffer holding the message, at least `padded_len(len)` bytes
+    /// * `len` - message length
+    pub fn pad<B: Block>(self, buf: &mut [u8], len: usize) -> Result<usize, ModeError> {
+        let padded_len = self.padded_len::<B>(len);
+        if buf.len() < padded_len || len > buf.len() {
+            return Err(ModeError::InvalidDataLength);
+        }
+
+        let padding = &mut buf[len..padded_len];
+        padding.fill(0);
+        if self != Self::Zero && !padding.is_empty() {
+            padding[0] = 0x80;
+        }
+        Ok(padded_len)
+    }
+
+    /// Returns length of the message in padded `data`
+    pub fn unpad<B: Block>(self, data: &[u8]) -> Result<usize, ModeError> {
+        if !data.len().is_multiple_of(B::SIZE) || (data.is_empty() && self == Self::Bit) {
+            return Err(ModeError::InvalidPadding);
+        }
+        if data.is_empty() {
+            return Ok(0);
+        }
+
+        // Trailing zeros of the last block and whether they follow 0x80
+        let last = &data[data.len() - B::SIZE..];
+        let mut zeros = 0usize;
+        let mut in_zeros = 0xFFu8;
+        let mut marked = 0u8;
+        for &byte in last.iter().rev() {
+            let zero = eq_mask(byte, 0);
+            marked |= in_zeros & !zero & eq_mask(byte, 0x80);
+            in_zeros &= zero;
+            zeros += (in_zeros & 1) as usize;
+        }
+
+        let len = data.len();
+        match self {
+            // The whole block can not be padding
+            Self::Zero => Ok(len - select(eq_mask(zeros as u8, B::SIZE as u8), B::SIZE - 1, zeros)),
+            Self::Bit if core::hint::black_box(marked) == 0xFF => Ok(len - zeros - 1),
+            Self::Bit => Err(ModeError::InvalidPadding),
+            Self::ConditionalBit => Ok(select(marked, len.wrapping_sub(zeros + 1), len)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROCEDURES: [Padding; 3] = [Padding::Zero, Padding::Bit, Padding::ConditionalBit];
+
+    #[test]
+    fn padded_lengths() {
+        assert_eq!(Padding::Zero.padded_len::<u64>(0), 0);
+        assert_eq!(Padding::Zero.padded_len::<u64>(9), 16);
+        assert_eq!(Padding::Bit.padded_len::<u64>(0), 8);
+        assert_eq!(Padding::Bit.padded_len::<u64>(8), 16);
+        assert_eq!(Padding::ConditionalBit.padded_len::<u64>(8), 8);
+        assert_eq!(Padding::ConditionalBit.padded_len::<u128>(17), 32);
+    }
+
+    #[test]
+    fn padding_bytes() {
+        let mut buf = [0xAAu8; 16];
+
+        assert_eq!(Padding::Zero.pad::<u64>(&mut buf, 5).unwrap(), 8);
+        assert_eq!(buf[5..8], [0, 0, 0]);
+
+        assert_eq!(Padding::Bit.pad::<u64>(&mut buf, 8).unwrap(), 16);
+        assert_eq!(buf[8..], [0x80, 0, 0, 0, 0, 0, 0, 0]);
+
+        buf[5..8].fill(0xAA);
+        assert_eq!(Padding::ConditionalBit.pad::<u64>(&mut buf, 5).unwrap(), 8);
+        assert_eq!(buf[5..8], [0x80, 0, 0]);
+        assert_eq!(Padding::ConditionalBit.pad::<u64>(&mut buf, 16).unwrap(), 16);
+    }
+
+    #[test]
+    fn pad_unpad_roundtrip() {
+        // Сообщения не оканчиваются нулём или 0x80, иначе процедуры 1 и 3 неоднозначны
+        let message: [u8; 40] = core::array::from_fn(|i| i as u8 + 1);
+
+        for padding in PROCEDURES {
+            for len in 0..=32 {
+                let mut buf = message;
+                let padded_len = padding.pad::<u64>(&mut buf, len).unwrap();
+                assert!(padded_len.is_multiple_of(8));
+                assert_eq!(padding.unpad::<u64>(&buf[..padded_len]).unwrap(), len, "{padding:?}, {len}");
+                assert_eq!(buf[..len], message[..len]);
+            }
+        }
+    }
+
+    #[test]
+    fn malformed_padding_is_rejected() {
+        let malformed: [&[u8]; 5] = [
+            &[],
+            &[1, 2, 3],
+            &[1, 2, 3, 4, 5, 6, 7, 0],
+            &[1, 2, 3, 4, 5, 0x80, 0, 1],
+            &[1, 2, 3, 4, 5, 0x81, 0, 0],
+        ];
+
+        for data in malformed {
+            assert!(matches!(Padding::Bit.unpad::<u64>(data), Err(ModeError::InvalidPadding)));
+        }
+        assert!(matches!(Padding::Bit.unpad::<u64>(&[0u8; 8]), Err(ModeError::InvalidPadding)));
+        assert!(matches!(Padding::Zero.unpad::<u64>(&[1u8; 9]), Err(ModeError::InvalidPadding)));
+        assert!(matches!(Padding::ConditionalBit.unpad::<u128>(&[1u8; 8]), Err(ModeError::InvalidPadding)));
+    }
+
+    #[test]
+    fn short_buffer_is_rejected() {
+        let mut buf = [0u8; 8];
+        assert!(matches!(Padding::Bit.pad::<u64>(&mut buf, 8), Err(ModeError::InvalidDataLength)));
+        assert!(matches!(Padding::Zero.pad::<u64>(&mut buf, 9), Err(ModeError::InvalidDataLength)));
+    }
+}
@@ -0,0 +1,61 @@
+use crate::core::cipher::kuznyechik::kuznyechik::Kuznyechik;
+use crate::core::ecc::hamming_7_4::Hamming74;
+use crate::core::pipeline::{Nibbles, Pipeline};
+
+/// KuznyechikHamming uses *Kuznyechik* and *Hamming(7,4)*, every nibble
+/// of the ciphertext is sent as a separate codeword
+pub type KuznyechikHamming = Pipeline<Kuznyechik, Hamming74, Nibbles, 32>;  // 16 байт × 2 кода
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::GeneralCipher;
+    use crate::test_purpose::*;
+
+    #[test]
+    fn frame_carries_ciphertext() {
+        use crate::core::ecc::ErrorCorrectionCode;
+        let cipher = KuznyechikHamming::new(KUZNYECHIK_KEY);
+        let frame = cipher.general_encrypt(KUZNYECHIK_PLAINTEXT).unwrap();
+
+        for (codewords, byte) in frame.chunks(2).zip(KUZNYECHIK_CIPHERTEXT.to_be_bytes()) {
+            assert_eq!(Hamming74.decode(codewords[0]).unwrap(), byte >> 4);
+            assert_eq!(Hamming74.decode(codewords[1]).unwrap(), byte & 0x0F);
+        }
+        assert_eq!(cipher.general_decrypt(frame).unwrap(), KUZNYECHIK_PLAINTEXT);
+    }
+
+    #[test]
+    fn single_bit_error_correctable() {
+        let cipher = KuznyechikHamming::new(KUZNYECHIK_KEY);
+        let encrypted = cipher.general_encrypt(KUZNYECHIK_PLAINTEXT).unwrap();
+
+        for byte_idx in 0..encrypted.len() {
+            for bit_idx in 0..7 {
+                let mut corrupted = encrypted;
+                corrupted[byte_idx] ^= 1 << bit_idx;
+                assert_eq!(cipher.general_decrypt(corrupted).unwrap(), KUZNYECHIK_PLAINTEXT);
+            }
+        }
+    }
+
+    #[test]
+    fn message_roundtrip() {
+        let cipher = KuznyechikHamming::new(KUZNYECHIK_KEY);
+        let message = kuznyechik_blocks_to_bytes(&KUZNYECHIK_MODES_PLAINTEXT);
+        let mut encrypted = [0u8; 3 * 32];
+        let mut decrypted = [0u8; 37];
+
+        let written = cipher.encrypt_into(&message[..37], &mut encrypted).unwrap();
+        assert_eq!(written, 3 * 32);
+        assert_eq!(cipher.decrypt_into(&encrypted, &mut decrypted).unwrap(), 37);
+        assert_eq!(decrypted, message[..37]);
+    }
+    #[test]
+    fn wipe_clears_key_material() {
+        let mut cipher = KuznyechikHamming::new(KUZNYECHIK_KEY);
+
+        cipher.wipe();
+        assert_eq!(cipher.cipher.round_keys, [[0u8; 16]; 10]);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::core::cipher::magma::magma::Magma;
+use crate::core::ecc::golay::Golay24;
+use crate::core::pipeline::{Bits, Pipeline};
+
+/// MagmaGolay uses *Magma* and extended *Golay(24,12)* for short control
+/// frames: up to three errors in every 3-byte codeword are corrected, four
+/// errors fail decoding
+pub type MagmaGolay = Pipeline<Magma, Golay24, Bits<12>, 18>;  // 6 кодов × 3 байта
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{GeneralCipher, GeneralCipherError};
+    use crate::test_purpose::*;
+
+    /// Calls `check` with every error pattern of 1-3 bits in 24 bits
+    fn for_each_pattern(mut check: impl FnMut(u32)) {
+        for a in 0..24 {
+            check(1 << a);
+            for b in a + 1..24 {
+                check(1 << a | 1 << b);
+                for c in b + 1..24 {
+                    check(1 << a | 1 << b | 1 << c);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn up_to_three_errors_in_every_codeword_corrected() {
+        let cipher = MagmaGolay::new(CIPHER_KEY);
+        let encrypted = cipher.general_encrypt(PLAINTEXT).unwrap();
+
+        for codeword in 0..6 {
+            for_each_pattern(|error| {
+                let mut corrupted = encrypted;
+                for (byte, mask) in corrupted[codeword * 3..][..3].iter_mut().zip(&error.to_be_bytes()[1..]) {
+                    *byte ^= mask;
+                }
+                assert_eq!(cipher.general_decrypt(corrupted).unwrap(), PLAINTEXT);
+            });
+        }
+    }
+
+    #[test]
+    fn three_errors_in_each_codeword_at_once() {
+        let cipher = MagmaGolay::new(CIPHER_KEY);
+        let mut corrupted = cipher.general_encrypt(PLAINTEXT).unwrap();
+
+        for codeword in corrupted.chunks_mut(3) {
+            codeword[0] ^= 0b1000_0001;
+            codeword[2] ^= 0b0001_0000;
+        }
+        assert_eq!(cipher.general_decrypt(corrupted).unwrap(), PLAINTEXT);
+    }
+
+    #[test]
+    fn four_errors_detected() {
+        let cipher = MagmaGolay::new(CIPHER_KEY);
+        let encrypted = cipher.general_encrypt(PLAINTEXT).unwrap();
+
+        for byte_idx in 0..encrypted.len() {
+            let mut corrupted = encrypted;
+            corrupted[byte_idx] ^= 0b1010_0101;
+            assert!(matches!(cipher.general_decrypt(corrupted), Err(GeneralCipherError::ECCDecodeError)));
+        }
+    }
+}
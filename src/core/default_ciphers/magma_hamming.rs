@@ -1,96 +1,17 @@
-use magma::utils;
-
-use crate::core::{GeneralCipher, GeneralCipherError};
-use crate::core::cipher::magma::magma::*;
-use crate::core::cipher::*;
-use crate::core::ecc::*;
-use crate::core::ecc::hamming_7_4::*;
-
-/// MagmaHamming uses *Hamming(7,4)* and *Magma*
-pub struct MagmaHamming {
-    crypto: Magma,
-    error_correction: Hamming74
-}
-
-impl Default for MagmaHamming {
-    fn default() -> Self {
-        Self {
-            crypto: MagmaBuilder::default().build(),
-            error_correction: Hamming74
-        }
-    }
-}
-
-impl Cipher for MagmaHamming {
-    type Input = u64;
-    type Output = u64;
-
-    fn encrypt(&self, data: Self::Input) -> Result<Self::Output, CipherError> {
-        self.crypto.encrypt(data)
-    }
-
-    fn decrypt(&self, data: Self::Output) -> Result<Self::Input, CipherError> {
-        self.crypto.decrypt(data)
-    }
-}
-
-impl ErrorCorrectionCode for MagmaHamming {
-    type Input = u8;
-    type Output = u8;
-
-    fn encode(&self, data: Self::Input) -> Result<Self::Output, EccError> {
-        self.error_correction.encode(data)
-    }
-
-    fn decode(&self, data: Self::Output) -> Result<Self::Input, EccError> {
-        self.error_correction.decode(data)
-    }
-}
+use crate::core::cipher::magma::magma::Magma;
+use crate::core::ecc::hamming_7_4::Hamming74;
+use crate::core::pipeline::{Nibbles, Pipeline};
 
-impl GeneralCipher for MagmaHamming {
-    type Input = u64;
-    type Output = [u8; 16];  // 8 байт × 2 кода
-
-    fn general_encrypt(&self, data: u64) -> Result<[u8; 16], GeneralCipherError> {
-        let ciphered = self.crypto
-            .encrypt(data)
-            .map_err(|_| GeneralCipherError::CipherEncryptError)?;
-        let bytes = ciphered.to_be_bytes();
-        let mut buf = [0u8; 16];
-
-        for (i, &byte) in bytes.iter().enumerate() {
-            let hi = (byte >> 4) & 0x0F;
-            let lo = byte & 0x0F;
-            buf[i * 2]     = self.error_correction.encode(hi)
-                                 .map_err(|_| GeneralCipherError::ECCEncodeError)?;
-            buf[i * 2 + 1] = self.error_correction.encode(lo)
-                                 .map_err(|_| GeneralCipherError::ECCEncodeError)?;
-        }
-
-        Ok(buf)
-    }
-
-    fn general_decrypt(&self, data: [u8; 16]) -> Result<u64, GeneralCipherError> {
-        let mut bytes = [0u8; 8];
-
-        for i in 0..8 {
-            let hi = self.error_correction.decode(data[i * 2])
-                          .map_err(|_| GeneralCipherError::ECCDecodeError)?;
-            let lo = self.error_correction.decode(data[i * 2 + 1])
-                          .map_err(|_| GeneralCipherError::ECCDecodeError)?;
-            bytes[i] = (hi << 4) | lo;
-        }
-
-        let value = u64::from_be_bytes(bytes);
-        self.crypto.decrypt(value)
-            .map_err(|_| GeneralCipherError::CipherDecryptError)
-    }
-}
+/// MagmaHamming uses *Magma* and *Hamming(7,4)*, every nibble
+/// of the ciphertext is sent as a separate codeword
+pub type MagmaHamming = Pipeline<Magma, Hamming74, Nibbles, 16>;  // 8 байт × 2 кода
 
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::cipher::Cipher;
+    use crate::core::GeneralCipher;
 
     #[test]
     fn roundtrip_without_errors() {
@@ -143,7 +64,7 @@ mod tests {
         let cipher = MagmaHamming::default();
         let input: u64 = 0xAABBCCDDEEFF0011;
 
-        let mut encrypted = cipher.general_encrypt(input).expect("encryption failed");
+        let encrypted = cipher.general_encrypt(input).expect("encryption failed");
 
         // Инвертируем один бит в каждом байте по очереди
         for byte_idx in 0..encrypted.len() {
@@ -189,4 +110,25 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn byte_and_word_keys_are_equivalent() {
+        use crate::test_purpose::{CIPHER_KEY, CIPHER_KEY_U8_ARRAY};
+        let from_words = MagmaHamming::new(CIPHER_KEY);
+        let from_bytes = MagmaHamming::new(CIPHER_KEY_U8_ARRAY);
+
+        let encrypted = from_words.general_encrypt(0x0123_4567_89AB_CDEF).unwrap();
+        assert_eq!(encrypted, from_bytes.general_encrypt(0x0123_4567_89AB_CDEF).unwrap());
+        assert_eq!(from_bytes.general_decrypt(encrypted).unwrap(), 0x0123_4567_89AB_CDEF);
+    }
+
+    #[test]
+    fn wipe_clears_key_material() {
+        use crate::test_purpose::CIPHER_KEY;
+        let mut cipher = MagmaHamming::new(CIPHER_KEY);
+
+        cipher.wipe();
+        assert_eq!(cipher.cipher.key, [0u32; 8]);
+        assert_eq!(cipher.cipher.round_keys, [0u32; 32]);
+    }
 }
@@ -0,0 +1,41 @@
+use crate::core::cipher::magma::magma::Magma;
+use crate::core::ecc::hamming_8_4::Hamming84;
+use crate::core::pipeline::{Nibbles, Pipeline};
+
+/// MagmaHamming84 uses *Magma* and extended *Hamming(8,4)*: single errors
+/// in a codeword are corrected, double errors fail decoding instead of
+/// being miscorrected
+pub type MagmaHamming84 = Pipeline<Magma, Hamming84, Nibbles, 16>;  // 8 байт × 2 кода
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{GeneralCipher, GeneralCipherError};
+    use crate::test_purpose::*;
+
+    #[test]
+    fn single_bit_error_correctable() {
+        let cipher = MagmaHamming84::new(CIPHER_KEY);
+        let encrypted = cipher.general_encrypt(PLAINTEXT).unwrap();
+
+        for byte_idx in 0..encrypted.len() {
+            for bit_idx in 0..8 {
+                let mut corrupted = encrypted;
+                corrupted[byte_idx] ^= 1 << bit_idx;
+                assert_eq!(cipher.general_decrypt(corrupted).unwrap(), PLAINTEXT);
+            }
+        }
+    }
+
+    #[test]
+    fn double_bit_error_detected() {
+        let cipher = MagmaHamming84::new(CIPHER_KEY);
+        let encrypted = cipher.general_encrypt(PLAINTEXT).unwrap();
+
+        for byte_idx in 0..encrypted.len() {
+            let mut corrupted = encrypted;
+            corrupted[byte_idx] ^= 0b1000_0001;
+            assert!(matches!(cipher.general_decrypt(corrupted), Err(GeneralCipherError::ECCDecodeError)));
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use core::cell::Cell;
+
+use crate::core::{GeneralCipher, GeneralCipherError};
+use crate::core::cipher::magma::key::Key;
+use crate::core::cipher::magma::magma::*;
+use crate::core::cipher::modes::{mgm::Mgm, ModeError};
+use crate::core::cipher::*;
+use crate::core::ecc::*;
+use crate::core::ecc::hamming_7_4::*;
+use crate::core::pipeline::{decode_symbols, encode_symbols, Nibbles};
+
+/// Frame before error correction: `nonce || ciphertext || tag`
+const FRAME_LEN: usize = 24;
+
+/// `MagmaMgmHamming` encrypts and authenticates data with *MGM* over *Magma*,
+/// then protects the whole frame `nonce || ciphertext || tag` with *Hamming(7,4)*,
+/// nibbles are encoded as in [`Pipeline`](crate::core::pipeline::Pipeline).
+///
+/// Nonces are taken from an internal counter, so the same key must never
+/// be used again with a counter that was reset.
+///
+/// Only single frames are authenticated. A message of several frames from
+/// [`GeneralCipher::encrypt_into`] has no integrity as a whole: every frame
+/// carries its own nonce, so reordered, dropped or spliced frames are
+/// accepted. Callers needing it must number messages and frames themselves.
+pub struct MagmaMgmHamming {
+    crypto: Magma,
+    error_correction: Hamming74,
+    nonce: Cell<u64>,
+}
+
+impl MagmaMgmHamming {
+    /// Constructs suite
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - session key given as `[u32; 8]` or `[u8; 32]`
+    /// * `initial_nonce` - first nonce to use, its most significant bit must be zero
+    pub fn new(key: impl Into<Key>, initial_nonce: u64) -> Result<Self, GeneralCipherError> {
+        if initial_nonce >> 63 != 0 {
+            return Err(GeneralCipherError::InvalidNonce);
+        }
+        Ok(Self {
+            crypto: MagmaBuilder::new().set_key(key).try_build()?,
+            error_correction: Hamming74,
+            nonce: Cell::new(initial_nonce),
+        })
+    }
+
+    /// Overwrites key material with zeros, the suite must not be used afterwards.
+    /// Key material is also wiped when the suite is dropped
+    pub fn wipe(&mut self) {
+        self.crypto.wipe();
+    }
+}
+
+impl Cipher for MagmaMgmHamming {
+    type Input = u64;
+    type Output = u64;
+
+    fn encrypt(&self, data: Self::Input) -> Result<Self::Output, CipherError> {
+        self.crypto.encrypt(data)
+    }
+
+    fn decrypt(&self, data: Self::Output) -> Result<Self::Input, CipherError> {
+        self.crypto.decrypt(data)
+    }
+}
+
+impl ErrorCorrectionCode for MagmaMgmHamming {
+    type Input = u8;
+    type Output = u8;
+
+    fn encode(&self, data: Self::Input) -> Result<Self::Output, EccError> {
+        self.error_correction.encode(data)
+    }
+
+    fn decode(&self, data: Self::Output) -> Result<Self::Input, EccError> {
+        self.error_correction.decode(data)
+    }
+}
+
+impl GeneralCipher for MagmaMgmHamming {
+    type Input = u64;
+    type Output = [u8; FRAME_LEN * 2];  // (8 байт nonce + 8 байт данных + 8 байт тега) × 2 кода
+
+    fn general_encrypt(&self, data: u64) -> Result<[u8; FRAME_LEN * 2], GeneralCipherError> {
+        // MGM требует нулевой старший бит nonce: счётчик исчерпан
+        let nonce = self.nonce.get();
+        if nonce >> 63 != 0 {
+            return Err(GeneralCipherError::InvalidNonce);
+        }
+
+        let mut frame = [0u8; FRAME_LEN];
+        let (header, rest) = frame.split_at_mut(8);
+        let (payload, tag) = rest.split_at_mut(8);
+
+        header.copy_from_slice(&nonce.to_be_bytes());
+        payload.copy_from_slice(&data.to_be_bytes());
+        Mgm::new(&self.crypto)
+            .encrypt(nonce, &[], payload, tag)
+            .map_err(|_| GeneralCipherError::CipherEncryptError)?;
+        self.nonce.set(nonce + 1);
+
+        let mut encoded = [0u8; FRAME_LEN * 2];
+        encode_symbols::<_, Nibbles>(&self.error_correction, &frame, &mut encoded)?;
+        Ok(encoded)
+    }
+
+    fn general_decrypt(&self, data: [u8; FRAME_LEN * 2]) -> Result<u64, GeneralCipherError> {
+        let mut frame = [0u8; FRAME_LEN];
+        decode_symbols::<_, Nibbles>(&self.error_correction, &data, &mut frame)?;
+        let (header, rest) = frame.split_at_mut(8);
+        let (payload, tag) = rest.split_at_mut(8);
+
+        let nonce = u64::from_be_bytes(header.try_into().unwrap());
+        Mgm::new(&self.crypto)
+            .decrypt(nonce, &[], payload, tag)
+            .map_err(|e| match e {
+                ModeError::TagMismatch => GeneralCipherError::AuthenticationError,
+                _ => GeneralCipherError::CipherDecryptError,
+            })?;
+
+        Ok(u64::from_be_bytes(payload.try_into().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip_without_errors() {
+        let cipher = MagmaMgmHamming::new(crate::test_purpose::CIPHER_KEY, 0).unwrap();
+        let samples = [
+            0x0000_0000_0000_0000,
+            0xFFFF_FFFF_FFFF_FFFF,
+            0x0123_4567_89AB_CDEF,
+        ];
+
+        for &plain in &samples {
+            let frame = cipher.general_encrypt(plain).expect("encryption failed");
+            let recovered = cipher.general_decrypt(frame).expect("decryption failed");
+            assert_eq!(recovered, plain);
+        }
+    }
+
+    #[test]
+    fn nonce_changes_between_frames() {
+        let cipher = MagmaMgmHamming::new(crate::test_purpose::CIPHER_KEY, 0).unwrap();
+
+        let first = cipher.general_encrypt(0xDEAD_BEEF).unwrap();
+        let second = cipher.general_encrypt(0xDEAD_BEEF).unwrap();
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn single_bit_errors_are_corrected() {
+        let cipher = MagmaMgmHamming::new(crate::test_purpose::CIPHER_KEY, 0).unwrap();
+        let input: u64 = 0xAABBCCDDEEFF0011;
+        let frame = cipher.general_encrypt(input).unwrap();
+
+        for byte_idx in 0..frame.len() {
+            for bit_idx in 0..7 {
+                let mut corrupted = frame;
+                corrupted[byte_idx] ^= 1 << bit_idx;
+                assert_eq!(cipher.general_decrypt(corrupted).unwrap(), input);
+            }
+        }
+    }
+
+    #[test]
+    fn nonces_with_most_significant_bit_are_rejected() {
+        use crate::test_purpose::CIPHER_KEY;
+        assert!(matches!(
+            MagmaMgmHamming::new(CIPHER_KEY, 1 << 63),
+            Err(GeneralCipherError::InvalidNonce)
+        ));
+
+        // Последний допустимый nonce, после него счётчик исчерпан
+        let cipher = MagmaMgmHamming::new(CIPHER_KEY, (1 << 63) - 1).unwrap();
+        let frame = cipher.general_encrypt(0x0123_4567_89AB_CDEF).unwrap();
+        assert_eq!(cipher.general_decrypt(frame).unwrap(), 0x0123_4567_89AB_CDEF);
+        assert!(matches!(
+            cipher.general_encrypt(0x0123_4567_89AB_CDEF),
+            Err(GeneralCipherError::InvalidNonce)
+        ));
+    }
+
+    #[test]
+    fn tampering_is_detected() {
+        let cipher = MagmaMgmHamming::new(crate::test_purpose::CIPHER_KEY, 0).unwrap();
+        let input: u64 = 0xAABBCCDDEEFF0011;
+        let frame = cipher.general_encrypt(input).unwrap();
+
+        // Заменяем кодовое слово целиком, чтобы Hamming не смог исправить ошибку
+        let mut forged = frame;
+        forged[20] = cipher.encode(cipher.decode(frame[20]).unwrap() ^ 0x1).unwrap();
+        assert!(matches!(
+            cipher.general_decrypt(forged),
+            Err(GeneralCipherError::AuthenticationError)
+        ));
+    }
+
+    #[test]
+    fn message_frames_are_authenticated() {
+        let cipher = MagmaMgmHamming::new(crate::test_purpose::CIPHER_KEY, 1).unwrap();
+        let message = *b"T=21.5C;P=1013hPa;V=3.30;ID=0042;OK!!";
+        let mut encrypted = [0u8; 5 * FRAME_LEN * 2];
+        let mut decrypted = [0u8; 37];
+
+        let written = cipher.encrypt_into(&message, &mut encrypted).unwrap();
+        assert_eq!(written, MagmaMgmHamming::encrypted_len(message.len()));
+        assert_eq!(cipher.decrypt_into(&encrypted, &mut decrypted).unwrap(), 37);
+        assert_eq!(decrypted, message);
+
+        // Every frame has its own nonce
+        assert_ne!(encrypted[..FRAME_LEN * 2], encrypted[FRAME_LEN * 2..FRAME_LEN * 4]);
+
+        encrypted[FRAME_LEN * 2 + 20] = cipher.encode(cipher.decode(encrypted[FRAME_LEN * 2 + 20]).unwrap() ^ 0x1).unwrap();
+        assert!(matches!(
+            cipher.decrypt_into(&encrypted, &mut decrypted),
+            Err(GeneralCipherError::AuthenticationError)
+        ));
+    }
+
+    #[test]
+    #[ignore = "known limitation: frames of a message are not bound together"]
+    fn reordered_frames_are_rejected() {
+        let cipher = MagmaMgmHamming::new(crate::test_purpose::CIPHER_KEY, 1).unwrap();
+        let message = *b"0123456789ABCDEF!";
+        let mut encrypted = [0u8; 3 * FRAME_LEN * 2];
+        let mut decrypted = [0u8; 17];
+
+        cipher.encrypt_into(&message, &mut encrypted).unwrap();
+        let (first, second) = encrypted.split_at_mut(FRAME_LEN * 2);
+        first.swap_with_slice(&mut second[..FRAME_LEN * 2]);
+
+        // Каждый кадр подлинный сам по себе, перестановку выявит только связь кадров
+        assert!(cipher.decrypt_into(&encrypted, &mut decrypted).is_err());
+    }
+
+    #[test]
+    fn wipe_clears_key_material() {
+        use crate::test_purpose::CIPHER_KEY;
+        let mut cipher = MagmaMgmHamming::new(CIPHER_KEY, 1).unwrap();
+
+        cipher.wipe();
+        assert_eq!(cipher.crypto.key, [0u32; 8]);
+        assert_eq!(cipher.crypto.round_keys, [0u32; 32]);
+    }
+}
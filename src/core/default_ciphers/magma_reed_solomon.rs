@@ -0,0 +1,46 @@
+use crate::core::cipher::magma::magma::Magma;
+use crate::core::ecc::reed_solomon::ReedSolomon;
+use crate::core::pipeline::{Chunks, Pipeline};
+
+/// MagmaReedSolomon uses *Magma* and shortened *RS(16, 8)*: the whole
+/// ciphertext block is one codeword, any 4 damaged bytes of the frame
+/// are corrected, including a burst of 32 bits
+pub type MagmaReedSolomon = Pipeline<Magma, ReedSolomon<16, 8>, Chunks<8>, 16>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::GeneralCipher;
+    use crate::test_purpose::*;
+
+    #[test]
+    fn frame_is_ciphertext_with_parity() {
+        let cipher = MagmaReedSolomon::new(CIPHER_KEY);
+        let frame = cipher.general_encrypt(PLAINTEXT).unwrap();
+
+        assert_eq!(frame[..8], CIPHERTEXT.to_be_bytes());
+        assert_eq!(cipher.general_decrypt(frame).unwrap(), PLAINTEXT);
+    }
+
+    #[test]
+    fn burst_error_correctable() {
+        let cipher = MagmaReedSolomon::new(CIPHER_KEY);
+        let encrypted = cipher.general_encrypt(PLAINTEXT).unwrap();
+
+        for start in 0..=12 {
+            let mut corrupted = encrypted;
+            corrupted[start..start + 4].iter_mut().for_each(|byte| *byte ^= 0xFF);
+            assert_eq!(cipher.general_decrypt(corrupted).unwrap(), PLAINTEXT);
+        }
+    }
+
+    #[test]
+    fn too_many_errors_are_not_hidden() {
+        let cipher = MagmaReedSolomon::new(CIPHER_KEY);
+        let mut corrupted = cipher.general_encrypt(PLAINTEXT).unwrap();
+        corrupted[..5].iter_mut().for_each(|byte| *byte ^= 0xFF);
+
+        let result = cipher.general_decrypt(corrupted);
+        assert!(!matches!(result, Ok(plain) if plain == PLAINTEXT));
+    }
+}
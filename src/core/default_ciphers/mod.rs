@@ -1,3 +1,8 @@
+pub mod kuznyechik_hamming;
+pub mod magma_golay;
 pub mod magma_hamming;
+pub mod magma_hamming84;
+pub mod magma_mgm_hamming;
 pub mod magma_noecc;
+pub mod magma_reed_solomon;
 pub mod magma_repetition;
\ No newline at end of file
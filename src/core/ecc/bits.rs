@@ -0,0 +1,38 @@
+//! Bit buffers: bits are numbered from the most significant bit of the first byte
+
+/// Returns `count <= 64` bits of `buf` starting at bit `offset`,
+/// bits past the end of `buf` are read as zeros
+pub(crate) fn read_bits(buf: &[u8], offset: usize, count: usize) -> u64 {
+    let mut value = 0u64;
+    for bit in offset..offset + count {
+        let set = buf.get(bit / 8).is_some_and(|byte| byte >> (7 - bit % 8) & 1 == 1);
+        value = (value << 1) | set as u64;
+    }
+    value
+}
+
+/// Writes `count <= 64` low bits of `value` into `buf` starting at bit `offset`
+pub(crate) fn write_bits(buf: &mut [u8], offset: usize, count: usize, value: u64) {
+    for (i, bit) in (offset..offset + count).enumerate() {
+        let mask = 1 << (7 - bit % 8);
+        if value >> (count - 1 - i) & 1 == 1 {
+            buf[bit / 8] |= mask;
+        } else {
+            buf[bit / 8] &= !mask;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bits_roundtrip() {
+        let mut buf = [0u8; 4];
+        write_bits(&mut buf, 3, 11, 0b101_1100_0111);
+        assert_eq!(buf, [0b0001_0111, 0b0001_1100, 0, 0]);
+        assert_eq!(read_bits(&buf, 3, 11), 0b101_1100_0111);
+        assert_eq!(read_bits(&buf, 30, 4), 0);
+    }
+}
@@ -0,0 +1,510 @@
+//! Rate 1/2 convolutional code with Viterbi decoding
+//!
+//! Generator polynomials are written as in the literature: the most
+//! significant of the `K` bits taps the newest input bit, so the NASA
+//! standard code is `K = 7`, `G1 = 171`, `G2 = 133` (octal). Rates 2/3 and
+//! 3/4 are obtained by puncturing, punctured bits are decoded as erasures.
+//!
+//! The decoder keeps a sliding window of `DEPTH` trellis steps, so memory
+//! does not depend on the message length.
+
+use super::*;
+use super::bits::{read_bits, write_bits};
+
+/// Traceback depth, well above `5K` for every supported `K`
+const DEPTH: usize = 64;
+/// Largest supported constraint length
+const MAX_CONSTRAINT_LENGTH: u8 = 9;
+/// Trellis steps decoded before and after a tail-biting message
+const WRAP: usize = DEPTH;
+/// Metric of unreachable states
+const UNREACHABLE: i32 = i32::MIN / 4;
+
+/// How the encoder starts and ends a message
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Termination {
+    /// The encoder starts from zero state and is flushed with `K - 1` zero bits
+    #[default]
+    ZeroTail,
+    /// The encoder starts from the state it ends in, no bits are added
+    TailBiting,
+}
+
+/// Which encoded bits are sent
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Puncturing {
+    /// Every bit, rate 1/2
+    #[default]
+    Rate1_2,
+    /// `G1: 11, G2: 10`, rate 2/3
+    Rate2_3,
+    /// `G1: 110, G2: 101`, rate 3/4
+    Rate3_4,
+}
+
+impl Puncturing {
+    /// Returns pattern over encoded bits `G1 G2 G1 G2 ...`
+    const fn pattern(self) -> &'static [bool] {
+        match self {
+            Self::Rate1_2 => &[true, true],
+            Self::Rate2_3 => &[true, true, true, false],
+            Self::Rate3_4 => &[true, true, true, false, false, true],
+        }
+    }
+
+    /// Returns number of sent bits among the first `mother_bits` encoded bits
+    fn sent_bits(self, mother_bits: usize) -> usize {
+        let pattern = self.pattern();
+        let kept = pattern.iter().filter(|&&kept| kept).count();
+        let rest = pattern[..mother_bits % pattern.len()].iter().filter(|&&kept| kept).count();
+        mother_bits / pattern.len() * kept + rest
+    }
+
+    /// Returns index of encoded bit `index` among sent bits, if it is sent
+    fn sent_index(self, index: usize) -> Option<usize> {
+        let pattern = self.pattern();
+        pattern[index % pattern.len()].then(|| self.sent_bits(index))
+    }
+}
+
+/// Convolutional code working on bit buffers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConvolutionalCode {
+    constraint_length: u8,
+    generators: [u16; 2],
+    termination: Termination,
+    puncturing: Puncturing,
+}
+
+impl ConvolutionalCode {
+    /// NASA standard code: `K = 7`, `G1 = 171`, `G2 = 133`
+    pub const NASA: Self = Self {
+        constraint_length: 7,
+        generators: [0o171, 0o133],
+        termination: Termination::ZeroTail,
+        puncturing: Puncturing::Rate1_2,
+    };
+
+    /// Constructs zero-tailed rate 1/2 code
+    ///
+    /// # Arguments
+    ///
+    /// * `constraint_length` - `K`, from 3 to 9
+    /// * `generators` - generator polynomials of `K` bits each, the most significant bit taps the newest input
+    pub fn new(constraint_length: u8, generators: [u16; 2]) -> Result<Self, EccError> {
+        let valid_length = (3..=MAX_CONSTRAINT_LENGTH).contains(&constraint_length);
+        if !valid_length || generators.iter().any(|&g| g == 0 || g >> constraint_length != 0) {
+            return Err(EccError::InvalidParameters);
+        }
+
+        Ok(Self { constraint_length, generators, ..Self::NASA })
+    }
+
+    /// Returns code with another termination
+    pub fn with_termination(self, termination: Termination) -> Self {
+        Self { termination, ..self }
+    }
+
+    /// Returns code with another rate
+    pub fn with_puncturing(self, puncturing: Puncturing) -> Self {
+        Self { puncturing, ..self }
+    }
+
+    /// Number of bits in encoder state
+    fn memory(&self) -> usize {
+        self.constraint_length as usize - 1
+    }
+
+    /// Returns number of trellis steps of a message of `bits` bits
+    fn steps(&self, bits: usize) -> usize {
+        match self.termination {
+            Termination::ZeroTail => bits + self.memory(),
+            Termination::TailBiting => bits,
+        }
+    }
+
+    /// Returns number of sent bits for a message of `bits` bits
+    pub fn encoded_bits(&self, bits: usize) -> usize {
+        self.puncturing.sent_bits(2 * self.steps(bits))
+    }
+
+    /// Returns both encoded bits `G1 G2` of the register
+    /// `input || state`, as the two low bits
+    #[inline]
+    fn output(&self, register: usize) -> usize {
+        let [g1, g2] = self.generators.map(|g| (register & g as usize).count_ones() as usize & 1);
+        (g1 << 1) | g2
+    }
+
+    fn check_message(&self, bits: usize) -> Result<(), EccError> {
+        if self.termination == Termination::TailBiting && bits < self.memory() {
+            return Err(EccError::InvalidParameters);
+        }
+        Ok(())
+    }
+
+    /// Encodes bits `data[..bits]` into `out`, returns number of written bits
+    pub fn encode_bits(&self, data: &[u8], bits: usize, out: &mut [u8]) -> Result<usize, EccError> {
+        self.check_message(bits)?;
+        let encoded_bits = self.encoded_bits(bits);
+        if bits > data.len() * 8 || encoded_bits > out.len() * 8 {
+            return Err(EccError::FailedToEncode);
+        }
+
+        let memory = self.memory();
+        let bit = |t: usize| if t < bits { read_bits(data, t, 1) as usize } else { 0 };
+
+        // Кольцевой код стартует из состояния, в котором закончит работу
+        let mut state = 0;
+        if self.termination == Termination::TailBiting {
+            for t in bits - memory..bits {
+                state = ((bit(t) << memory) | state) >> 1;
+            }
+        }
+
+        for t in 0..self.steps(bits) {
+            let register = (bit(t) << memory) | state;
+            let output = self.output(register);
+            for j in 0..2 {
+                if let Some(index) = self.puncturing.sent_index(2 * t + j) {
+                    write_bits(out, index, 1, (output >> (1 - j) & 1) as u64);
+                }
+            }
+            state = register >> 1;
+        }
+
+        Ok(encoded_bits)
+    }
+
+    /// Decodes hard bits written by `encode_bits` into `out[..bits]`
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - received bits
+    /// * `bits` - length of the message
+    /// * `out` - buffer for the message
+    pub fn decode_bits(&self, data: &[u8], bits: usize, out: &mut [u8]) -> Result<(), EccError> {
+        if self.encoded_bits(bits) > data.len() * 8 {
+            return Err(EccError::FailedToDecode);
+        }
+        self.viterbi(bits, |index| if read_bits(data, index, 1) == 0 { 1 } else { -1 }, out)
+    }
+
+    /// Decodes soft bits into `out[..bits]`
+    ///
+    /// # Arguments
+    ///
+    /// * `llrs` - log-likelihood ratios `ln(P(0) / P(1))` of every sent bit, `0` for unknown bits
+    /// * `bits` - length of the message
+    /// * `out` - buffer for the message
+    pub fn decode_soft(&self, llrs: &[i8], bits: usize, out: &mut [u8]) -> Result<(), EccError> {
+        if self.encoded_bits(bits) != llrs.len() {
+            return Err(EccError::FailedToDecode);
+        }
+        self.viterbi(bits, |index| llrs[index] as i32, out)
+    }
+
+    /// Runs Viterbi algorithm maximizing correlation of encoded bits with `llr`
+    fn viterbi(&self, bits: usize, llr: impl Fn(usize) -> i32, out: &mut [u8]) -> Result<(), EccError> {
+        self.check_message(bits)?;
+        if bits > out.len() * 8 {
+            return Err(EccError::FailedToDecode);
+        }
+
+        let memory = self.memory();
+        let states = 1 << memory;
+        let mut outputs = [0u8; 1 << MAX_CONSTRAINT_LENGTH];
+        for (register, output) in outputs[..states * 2].iter_mut().enumerate() {
+            *output = self.output(register) as u8;
+        }
+
+        // Кольцевой код декодируется по кругу: окно до и после сообщения
+        // позволяет начать и закончить с правильными состояниями
+        let (first, steps, mut metrics) = match self.termination {
+            Termination::ZeroTail => {
+                let mut metrics = [UNREACHABLE; 1 << (MAX_CONSTRAINT_LENGTH - 1)];
+                metrics[0] = 0;
+                (0, self.steps(bits), metrics)
+            }
+            Termination::TailBiting => (WRAP, WRAP + bits + WRAP, [0; 1 << (MAX_CONSTRAINT_LENGTH - 1)]),
+        };
+        let message_step = |step: usize| match self.termination {
+            Termination::ZeroTail => step,
+            Termination::TailBiting => (step + bits * WRAP - WRAP) % bits,
+        };
+
+        let mut decisions = [[0u64; 4]; DEPTH];
+        let mut emit = |decisions: &[[u64; 4]; DEPTH], last: usize, from: usize, mut state: usize| {
+            for step in (from..=last).rev() {
+                let message_bit = step.wrapping_sub(first);
+                if step >= first && message_bit < bits {
+                    write_bits(out, message_bit, 1, (state >> (memory - 1)) as u64);
+                }
+                let decision = decisions[step % DEPTH][state / 64] >> (state % 64) & 1;
+                state = ((state << 1) & (states - 1)) | decision as usize;
+            }
+        };
+
+        for step in 0..steps {
+            let t = message_step(step);
+            let [sent_g1, sent_g2] = [2 * t, 2 * t + 1].map(|index| self.puncturing.sent_index(index).map_or(0, &llr));
+            let branch = [sent_g1 + sent_g2, sent_g1 - sent_g2, sent_g2 - sent_g1, -sent_g1 - sent_g2];
+
+            let mut next = [UNREACHABLE; 1 << (MAX_CONSTRAINT_LENGTH - 1)];
+            let step_decisions = &mut decisions[step % DEPTH];
+            *step_decisions = [0; 4];
+            for (state, metric) in next[..states].iter_mut().enumerate() {
+                let input = state >> (memory - 1);
+                let previous = (state << 1) & (states - 1);
+                let [zero, one] = [previous, previous | 1].map(|previous| {
+                    metrics[previous] + branch[outputs[(input << memory) | previous] as usize]
+                });
+                if one > zero {
+                    step_decisions[state / 64] |= 1 << (state % 64);
+                }
+                *metric = zero.max(one);
+            }
+
+            let best = next[..states].iter().copied().max().unwrap_or(0);
+            for metric in next[..states].iter_mut() {
+                *metric = (*metric - best).max(UNREACHABLE);
+            }
+            metrics = next;
+
+            if step + 1 >= DEPTH && step + 1 < steps {
+                let state = (0..states).max_by_key(|&state| metrics[state]).unwrap_or(0);
+                emit(&decisions, step, step + 1 - DEPTH, state);
+            }
+        }
+
+        // Хвост окна: нулевой код заканчивается в нулевом состоянии
+        let last = steps - 1;
+        let state = match self.termination {
+            Termination::ZeroTail => 0,
+            Termination::TailBiting => (0..states).max_by_key(|&state| metrics[state]).unwrap_or(0),
+        };
+        emit(&decisions, last, (last + 1).saturating_sub(DEPTH), state);
+
+        Ok(())
+    }
+}
+
+impl Default for ConvolutionalCode {
+    fn default() -> Self {
+        Self::NASA
+    }
+}
+
+/// `ConvolutionalCode` over messages of `N` bytes encoded into `M` bytes
+pub struct Convolutional<const N: usize, const M: usize> {
+    code: ConvolutionalCode,
+}
+
+impl<const N: usize, const M: usize> Convolutional<N, M> {
+    /// Constructs code, `M` must be the encoded length of `N` bytes in whole bytes
+    pub fn new(code: ConvolutionalCode) -> Result<Self, EccError> {
+        if code.check_message(8 * N).is_err() || code.encoded_bits(8 * N).div_ceil(8) != M {
+            return Err(EccError::InvalidParameters);
+        }
+        Ok(Self { code })
+    }
+
+    /// Returns underlying code
+    pub fn code(&self) -> &ConvolutionalCode {
+        &self.code
+    }
+}
+
+impl<const N: usize, const M: usize> Default for Convolutional<N, M> {
+    /// NASA standard code, `M` must be `2N + 2`
+    fn default() -> Self {
+        const { assert!(M == 2 * N + 2, "Convolutional: M does not match the NASA code") };
+        Self { code: ConvolutionalCode::NASA }
+    }
+}
+
+impl<const N: usize, const M: usize> ErrorCorrectionCode for Convolutional<N, M> {
+    type Input = [u8; N];
+    type Output = [u8; M];
+
+    fn encode(&self, data: Self::Input) -> Result<Self::Output, EccError> {
+        let mut encoded = [0u8; M];
+        self.code.encode_bits(&data, 8 * N, &mut encoded)?;
+        Ok(encoded)
+    }
+
+    fn decode(&self, data: Self::Output) -> Result<Self::Input, EccError> {
+        let mut decoded = [0u8; N];
+        self.code.decode_bits(&data, 8 * N, &mut decoded)?;
+        Ok(decoded)
+    }
+}
+
+impl<const N: usize, const M: usize> SoftDecisionCode for Convolutional<N, M> {
+    fn decode_soft(&self, llrs: &[i8]) -> Result<Self::Input, EccError> {
+        let mut decoded = [0u8; N];
+        self.code.decode_soft(llrs, 8 * N, &mut decoded)?;
+        Ok(decoded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_purpose::channel_llr;
+    use crate::test_purpose::XorShift64;
+
+    const TERMINATIONS: [Termination; 2] = [Termination::ZeroTail, Termination::TailBiting];
+    const PUNCTURINGS: [Puncturing; 3] = [Puncturing::Rate1_2, Puncturing::Rate2_3, Puncturing::Rate3_4];
+
+    fn flip(buf: &mut [u8], bit: usize) {
+        buf[bit / 8] ^= 0x80 >> (bit % 8);
+    }
+
+    #[test]
+    fn nasa_impulse_response() {
+        // Один единичный бит даёт на выходе сами порождающие многочлены
+        let mut encoded = [0u8; 2];
+        ConvolutionalCode::NASA.encode_bits(&[0x80], 1, &mut encoded).unwrap();
+
+        // G1 = 1111001, G2 = 1011011, выход чередуется G1 G2
+        assert_eq!(u16::from_be_bytes(encoded) >> 2, 0b11_10_11_11_00_01_11);
+    }
+
+    #[test]
+    fn encoded_lengths() {
+        let code = ConvolutionalCode::NASA;
+        assert_eq!(code.encoded_bits(100), 212);
+        assert_eq!(code.with_puncturing(Puncturing::Rate2_3).encoded_bits(100), 159);
+        assert_eq!(code.with_puncturing(Puncturing::Rate3_4).encoded_bits(102), 144);
+        assert_eq!(code.with_termination(Termination::TailBiting).encoded_bits(100), 200);
+    }
+
+    #[test]
+    fn roundtrip_every_mode() {
+        let mut rng = XorShift64(0x0123_4567_89AB_CDEF);
+        let message: [u8; 40] = core::array::from_fn(|_| rng.next_u64() as u8);
+
+        for termination in TERMINATIONS {
+            for puncturing in PUNCTURINGS {
+                let code = ConvolutionalCode::NASA.with_termination(termination).with_puncturing(puncturing);
+                for bits in [6, 13, 64, 200, 320] {
+                    let mut encoded = [0u8; 96];
+                    let mut decoded = [0u8; 40];
+                    let written = code.encode_bits(&message, bits, &mut encoded).unwrap();
+                    code.decode_bits(&encoded, bits, &mut decoded).unwrap();
+
+                    assert!((0..bits).all(|bit| read_bits(&decoded, bit, 1) == read_bits(&message, bit, 1)),
+                        "{termination:?} {puncturing:?} {bits} bits, {written} sent");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn scattered_errors_are_corrected() {
+        let mut rng = XorShift64(0xA5A5_5A5A_0F0F_F0F0);
+        let message: [u8; 32] = core::array::from_fn(|_| rng.next_u64() as u8);
+
+        for (termination, puncturing, spacing) in [
+            (Termination::ZeroTail, Puncturing::Rate1_2, 12),
+            (Termination::TailBiting, Puncturing::Rate1_2, 12),
+            (Termination::ZeroTail, Puncturing::Rate2_3, 24),
+            (Termination::ZeroTail, Puncturing::Rate3_4, 40),
+        ] {
+            let code = ConvolutionalCode::NASA.with_termination(termination).with_puncturing(puncturing);
+            let mut encoded = [0u8; 70];
+            let written = code.encode_bits(&message, 256, &mut encoded).unwrap();
+            for bit in (5..written).step_by(spacing) {
+                flip(&mut encoded, bit);
+            }
+
+            let mut decoded = [0u8; 32];
+            code.decode_bits(&encoded, 256, &mut decoded).unwrap();
+            assert_eq!(decoded, message, "{termination:?} {puncturing:?}");
+        }
+    }
+
+    #[test]
+    fn soft_decisions_use_reliability() {
+        let code = ConvolutionalCode::NASA;
+        let message = *b"soft";
+        let mut encoded = [0u8; 10];
+        let written = code.encode_bits(&message, 32, &mut encoded).unwrap();
+
+        // Каждый третий бит перевёрнут, но с малой уверенностью
+        let llrs: [i8; 76] = core::array::from_fn(|i| {
+            let llr = if read_bits(&encoded, i, 1) == 0 { 100 } else { -100 };
+            if i % 3 == 0 { -llr / 10 } else { llr }
+        });
+        assert_eq!(written, llrs.len());
+
+        let mut decoded = [0u8; 4];
+        code.decode_soft(&llrs, 32, &mut decoded).unwrap();
+        assert_eq!(decoded, message);
+
+        let mut hard = [0u8; 10];
+        for (i, &llr) in llrs.iter().enumerate() {
+            write_bits(&mut hard, i, 1, (llr < 0) as u64);
+        }
+        code.decode_bits(&hard, 32, &mut decoded).unwrap();
+        assert_ne!(decoded, message);
+    }
+
+    #[test]
+    fn configurable_generators() {
+        // K = 3, G = (7, 5): свободное расстояние 5, исправляет две ошибки
+        let code = ConvolutionalCode::new(3, [0o7, 0o5]).unwrap();
+        let message = [0xC3, 0x5A];
+        let mut encoded = [0u8; 5];
+        code.encode_bits(&message, 16, &mut encoded).unwrap();
+        flip(&mut encoded, 3);
+        flip(&mut encoded, 20);
+
+        let mut decoded = [0u8; 2];
+        code.decode_bits(&encoded, 16, &mut decoded).unwrap();
+        assert_eq!(decoded, message);
+
+        assert!(matches!(ConvolutionalCode::new(2, [3, 1]), Err(EccError::InvalidParameters)));
+        assert!(matches!(ConvolutionalCode::new(7, [0o171, 0o400]), Err(EccError::InvalidParameters)));
+        assert!(matches!(ConvolutionalCode::new(10, [1, 1]), Err(EccError::InvalidParameters)));
+    }
+
+    #[test]
+    fn byte_blocks_as_error_correction_code() {
+        let code = Convolutional::<8, 18>::default();
+        let data = *b"telemtry";
+        let mut encoded = code.encode(data).unwrap();
+        flip(&mut encoded, 10);
+        flip(&mut encoded, 70);
+        assert_eq!(code.decode(encoded).unwrap(), data);
+
+        let punctured = ConvolutionalCode::NASA.with_puncturing(Puncturing::Rate3_4);
+        assert!(Convolutional::<8, 12>::new(punctured).is_ok());
+        assert!(matches!(Convolutional::<8, 18>::new(punctured), Err(EccError::InvalidParameters)));
+    }
+
+    #[test]
+    fn soft_decoding_lowers_ber() {
+        let code = Convolutional::<8, 18>::default();
+        let mut rng = XorShift64(0x7E57_0000_0000_0C07);
+        let (mut hard_errors, mut soft_errors) = (0, 0);
+
+        for _ in 0..300 {
+            let data = rng.next_u64().to_be_bytes();
+            let encoded = code.encode(data).unwrap();
+            let llrs: [i8; 140] = core::array::from_fn(|i| channel_llr(read_bits(&encoded, i, 1) == 1, &mut rng, 26));
+
+            let mut hard = [0u8; 18];
+            for (i, &llr) in llrs.iter().enumerate() {
+                write_bits(&mut hard, i, 1, (llr < 0) as u64);
+            }
+
+            let errors = |decoded: [u8; 8]| u64::from_be_bytes(decoded) ^ u64::from_be_bytes(data);
+            hard_errors += errors(code.decode(hard).unwrap()).count_ones();
+            soft_errors += errors(code.decode_soft(&llrs).unwrap()).count_ones();
+        }
+
+        assert!(soft_errors * 3 < hard_errors, "hard: {hard_errors}, soft: {soft_errors}");
+    }
+}
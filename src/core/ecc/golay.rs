@@ -0,0 +1,186 @@
+//! Binary Golay code (23,12) and extended Golay code (24,12)
+//!
+//! *Golay(23,12)* is a perfect cyclic code: every 11-bit syndrome matches
+//! exactly one error pattern of at most three bits, so decoding is a lookup
+//! in a table built at compile time.
+
+use super::*;
+
+/// Generator polynomial `x^11 + x^10 + x^6 + x^5 + x^4 + x^2 + 1`
+const GENERATOR: u32 = 0xC75;
+
+/// Returns remainder of a 23-bit `word` divided by the generator polynomial
+const fn syndrome(word: u32) -> u32 {
+    let mut word = word;
+    let mut bit = 22;
+    while bit >= 11 {
+        if word >> bit & 1 == 1 {
+            word ^= GENERATOR << (bit - 11);
+        }
+        bit -= 1;
+    }
+    word
+}
+
+/// Error patterns of up to three bits indexed by syndrome
+const ERRORS: [u32; 2048] = {
+    let mut table = [0u32; 2048];
+    let mut a = 0;
+    while a < 23 {
+        table[syndrome(1 << a) as usize] = 1 << a;
+        let mut b = a + 1;
+        while b < 23 {
+            table[syndrome(1 << a | 1 << b) as usize] = 1 << a | 1 << b;
+            let mut c = b + 1;
+            while c < 23 {
+                table[syndrome(1 << a | 1 << b | 1 << c) as usize] = 1 << a | 1 << b | 1 << c;
+                c += 1;
+            }
+            b += 1;
+        }
+        a += 1;
+    }
+    table
+};
+
+/// Golay(23,12): corrects up to three errors in a codeword
+#[derive(Default)]
+pub struct Golay23;
+
+impl ErrorCorrectionCode for Golay23 {
+    type Input = u16;  // 12 младших бит
+    type Output = u32; // 23 младших бита: данные || 11 проверочных бит
+
+    fn encode(&self, data: Self::Input) -> Result<Self::Output, EccError> {
+        if data >> 12 != 0 {
+            return Err(EccError::FailedToEncode);
+        }
+
+        let shifted = (data as u32) << 11;
+        Ok(shifted | syndrome(shifted))
+    }
+
+    fn decode(&self, data: Self::Output) -> Result<Self::Input, EccError> {
+        if data >> 23 != 0 {
+            return Err(EccError::FailedToDecode);
+        }
+
+        let error = ERRORS[syndrome(data) as usize];
+        Ok(((data ^ error) >> 11) as u16)
+    }
+}
+
+/// Extended Golay(24,12): *Golay(23,12)* with the most significant bit
+/// holding parity of the whole codeword, sent as 3 bytes. Corrects up to
+/// three errors and detects four
+#[derive(Default)]
+pub struct Golay24;
+
+impl ErrorCorrectionCode for Golay24 {
+    type Input = u16;     // 12 младших бит
+    type Output = [u8; 3]; // чётность || Golay(23,12), big-endian
+
+    fn encode(&self, data: Self::Input) -> Result<Self::Output, EccError> {
+        let encoded = Golay23.encode(data)?;
+        let parity = encoded.count_ones() & 1;
+        let [_, codeword @ ..] = ((parity << 23) | encoded).to_be_bytes();
+
+        Ok(codeword)
+    }
+
+    fn decode(&self, data: Self::Output) -> Result<Self::Input, EccError> {
+        let [b0, b1, b2] = data;
+        let word = u32::from_be_bytes([0, b0, b1, b2]);
+        let error = ERRORS[syndrome(word & 0x7F_FFFF) as usize];
+
+        // После исправления чётность должна сойтись: иначе ошибся и бит чётности.
+        // Четыре ошибки всегда дают исправление веса четыре
+        let parity_error = (word ^ error).count_ones() & 1;
+        if error.count_ones() + parity_error > 3 {
+            return Err(EccError::FailedToDecode);
+        }
+
+        Ok(((word ^ error) >> 11 & 0xFFF) as u16)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Calls `check` with every error pattern of `weight` bits among `n` low bits
+    fn for_each_pattern(n: usize, weight: u32, check: &mut impl FnMut(u32)) {
+        fn extend(from: usize, n: usize, left: u32, pattern: u32, check: &mut impl FnMut(u32)) {
+            if left == 0 {
+                return check(pattern);
+            }
+            for bit in from..n {
+                extend(bit + 1, n, left - 1, pattern | 1 << bit, check);
+            }
+        }
+        extend(0, n, weight, 0, check);
+    }
+
+    #[test]
+    fn codeword_weights() {
+        // Ненулевые слова (23,12) весят не меньше 7, слова (24,12) кратны 4 и весят не меньше 8
+        for data in 1u16..1 << 12 {
+            assert!(Golay23.encode(data).unwrap().count_ones() >= 7);
+
+            let weight: u32 = Golay24.encode(data).unwrap().iter().map(|byte| byte.count_ones()).sum();
+            assert!(weight >= 8 && weight.is_multiple_of(4), "weight {} of {:03x}", weight, data);
+        }
+    }
+
+    #[test]
+    fn every_error_of_up_to_three_bits_is_corrected() {
+        for data in (0u16..1 << 12).step_by(7) {
+            let encoded = Golay23.encode(data).unwrap();
+            assert_eq!(Golay23.decode(encoded).unwrap(), data);
+
+            for weight in 1..=3 {
+                for_each_pattern(23, weight, &mut |error| {
+                    assert_eq!(Golay23.decode(encoded ^ error).unwrap(), data, "error {:06x}", error);
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn extended_code_corrects_up_to_three_errors() {
+        for data in (0u16..1 << 12).step_by(7) {
+            let [b0, b1, b2] = Golay24.encode(data).unwrap();
+            let encoded = u32::from_be_bytes([0, b0, b1, b2]);
+
+            for weight in 1..=3 {
+                for_each_pattern(24, weight, &mut |error| {
+                    let [_, corrupted @ ..] = (encoded ^ error).to_be_bytes();
+                    assert_eq!(Golay24.decode(corrupted).unwrap(), data, "error {:06x}", error);
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn extended_code_detects_four_errors() {
+        for data in [0x000, 0x5A3, 0xFFF] {
+            let [b0, b1, b2] = Golay24.encode(data).unwrap();
+            let encoded = u32::from_be_bytes([0, b0, b1, b2]);
+
+            for_each_pattern(24, 4, &mut |error| {
+                let [_, corrupted @ ..] = (encoded ^ error).to_be_bytes();
+                assert!(
+                    matches!(Golay24.decode(corrupted), Err(EccError::FailedToDecode)),
+                    "четыре ошибки не обнаружены: {:06x}", error
+                );
+            });
+        }
+    }
+
+    #[test]
+    fn out_of_range_words_are_rejected() {
+        assert!(matches!(Golay23.encode(1 << 12), Err(EccError::FailedToEncode)));
+        assert!(matches!(Golay24.encode(1 << 12), Err(EccError::FailedToEncode)));
+        assert!(matches!(Golay23.decode(1 << 23), Err(EccError::FailedToDecode)));
+    }
+}
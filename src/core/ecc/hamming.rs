@@ -0,0 +1,249 @@
+//! Hamming codes `(2^M - 1, 2^M - M - 1)` in systematic form: a codeword is
+//! `data || parity`, with `M` parity bits in the least significant positions.
+//!
+//! A shortened code `Hamming<M, S>` drops the `S` most significant data bits,
+//! which are taken as zeros, so it is `(2^M - 1 - S, 2^M - M - 1 - S)`.
+
+use super::*;
+use super::bits::{read_bits, write_bits};
+
+/// Marks syndromes which do not point to any bit of a shortened code
+const UNCORRECTABLE: u64 = u64::MAX;
+
+/// Hamming code with `M` parity bits, shortened by `S` data bits, `2 <= M <= 6`
+#[derive(Default)]
+pub struct Hamming<const M: usize, const S: usize = 0>;
+
+/// Hamming(15,11)
+pub type Hamming15_11 = Hamming<4>;
+/// Hamming(31,26)
+pub type Hamming31_26 = Hamming<5>;
+/// Hamming(63,57)
+pub type Hamming63_57 = Hamming<6>;
+
+/// Returns column of the parity-check matrix for data bit `index`:
+/// data bits take all `M`-bit numbers which are not powers of two
+const fn data_column(index: usize) -> u64 {
+    let mut column = 2u64;
+    let mut left = index + 1;
+    while left > 0 {
+        column += 1;
+        if !column.is_power_of_two() {
+            left -= 1;
+        }
+    }
+    column
+}
+
+impl<const M: usize, const S: usize> Hamming<M, S> {
+    /// Codeword length in bits
+    pub const N: usize = (1 << M) - 1 - S;
+    /// Data length in bits
+    pub const K: usize = Self::N - M;
+
+    const VALID: () = assert!(M >= 2 && M <= 6 && S < (1 << M) - M - 1, "Hamming: unsupported M or S");
+
+    /// Parity-check matrix columns of data bits, least significant bit first
+    const COLUMNS: [u64; 64] = {
+        let mut columns = [0u64; 64];
+        let mut i = 0;
+        while i < Self::K {
+            columns[i] = data_column(i);
+            i += 1;
+        }
+        columns
+    };
+
+    /// Error patterns indexed by syndrome
+    const SYNDROMES: [u64; 64] = {
+        let mut table = [UNCORRECTABLE; 64];
+        table[0] = 0;
+        let mut i = 0;
+        while i < M {
+            table[1 << i] = 1 << i;
+            i += 1;
+        }
+        let mut i = 0;
+        while i < Self::K {
+            table[Self::COLUMNS[i] as usize] = 1 << (M + i);
+            i += 1;
+        }
+        table
+    };
+
+    fn parity(data: u64) -> u64 {
+        let mut parity = 0;
+        for (i, column) in Self::COLUMNS[..Self::K].iter().enumerate() {
+            parity ^= column & (data >> i & 1).wrapping_neg();
+        }
+        parity
+    }
+
+    /// Encodes bits `data[..bits]`, splitting them into `K`-bit words; the last
+    /// word is padded with zeros. Codewords are written one after another into
+    /// `out`, returns number of written bits
+    pub fn encode_bits(&self, data: &[u8], bits: usize, out: &mut [u8]) -> Result<usize, EccError> {
+        let words = bits.div_ceil(Self::K);
+        if bits > data.len() * 8 || words * Self::N > out.len() * 8 {
+            return Err(EccError::FailedToEncode);
+        }
+
+        for word in 0..words {
+            let count = Self::K.min(bits - word * Self::K);
+            let value = read_bits(data, word * Self::K, count) << (Self::K - count);
+            write_bits(out, word * Self::N, Self::N, self.encode(value)?);
+        }
+        Ok(words * Self::N)
+    }
+
+    /// Decodes `bits` bits of codewords written by `encode_bits` into `out`,
+    /// returns number of written bits, including the zero padding of the last word
+    pub fn decode_bits(&self, data: &[u8], bits: usize, out: &mut [u8]) -> Result<usize, EccError> {
+        let words = bits / Self::N;
+        if !bits.is_multiple_of(Self::N) || bits > data.len() * 8 || words * Self::K > out.len() * 8 {
+            return Err(EccError::FailedToDecode);
+        }
+
+        for word in 0..words {
+            let value = self.decode(read_bits(data, word * Self::N, Self::N))?;
+            write_bits(out, word * Self::K, Self::K, value);
+        }
+        Ok(words * Self::K)
+    }
+}
+
+impl<const M: usize, const S: usize> ErrorCorrectionCode for Hamming<M, S> {
+    type Input = u64;  // K младших бит
+    type Output = u64; // N младших бит: данные || проверочные биты
+
+    fn encode(&self, data: Self::Input) -> Result<Self::Output, EccError> {
+        let () = Self::VALID;
+        if data >> Self::K != 0 {
+            return Err(EccError::FailedToEncode);
+        }
+
+        Ok((data << M) | Self::parity(data))
+    }
+
+    fn decode(&self, data: Self::Output) -> Result<Self::Input, EccError> {
+        let () = Self::VALID;
+        if data >> Self::N != 0 {
+            return Err(EccError::FailedToDecode);
+        }
+
+        let syndrome = (data & ((1 << M) - 1)) ^ Self::parity(data >> M);
+        let error = Self::SYNDROMES[syndrome as usize];
+        if error == UNCORRECTABLE {
+            return Err(EccError::FailedToDecode);
+        }
+
+        Ok((data ^ error) >> M)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_purpose::XorShift64;
+
+    fn check_single_errors<const M: usize, const S: usize>() {
+        let code = Hamming::<M, S>;
+        let mut rng = XorShift64(0x2545_F491_4F6C_DD1D ^ (M * 64 + S) as u64);
+
+        for _ in 0..32 {
+            let data = rng.next_u64() & ((1 << Hamming::<M, S>::K) - 1);
+            let encoded = code.encode(data).unwrap();
+            assert_eq!(code.decode(encoded).unwrap(), data);
+
+            for bit in 0..Hamming::<M, S>::N {
+                assert_eq!(
+                    code.decode(encoded ^ (1 << bit)).unwrap(), data,
+                    "({}, {}): failed to correct bit {}",
+                    Hamming::<M, S>::N, Hamming::<M, S>::K, bit
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parameters() {
+        assert_eq!((Hamming15_11::N, Hamming15_11::K), (15, 11));
+        assert_eq!((Hamming31_26::N, Hamming31_26::K), (31, 26));
+        assert_eq!((Hamming63_57::N, Hamming63_57::K), (63, 57));
+        assert_eq!((Hamming::<6, 25>::N, Hamming::<6, 25>::K), (38, 32));
+    }
+
+    #[test]
+    fn matches_hamming_7_4_distance() {
+        // Все 16 кодовых слов (7,4) попарно отличаются минимум в 3 битах
+        let code = Hamming::<3>;
+        for a in 0..16u64 {
+            for b in (a + 1)..16 {
+                let distance = (code.encode(a).unwrap() ^ code.encode(b).unwrap()).count_ones();
+                assert!(distance >= 3);
+            }
+        }
+    }
+
+    #[test]
+    fn every_single_bit_error_is_corrected() {
+        check_single_errors::<3, 0>();
+        check_single_errors::<4, 0>();
+        check_single_errors::<5, 0>();
+        check_single_errors::<6, 0>();
+    }
+
+    #[test]
+    fn shortened_codes_correct_single_errors() {
+        check_single_errors::<4, 3>();
+        check_single_errors::<5, 10>();
+        check_single_errors::<6, 25>();
+    }
+
+    #[test]
+    fn shortened_code_rejects_missing_positions() {
+        // (12,8): синдромы отброшенных столбцов не указывают ни на один бит
+        let code = Hamming::<4, 3>;
+        let encoded = code.encode(0xA5).unwrap();
+        let failures = (0..12)
+            .flat_map(|b1| (b1 + 1..12).map(move |b2| (b1, b2)))
+            .filter(|&(b1, b2)| code.decode(encoded ^ (1 << b1) ^ (1 << b2)).is_err())
+            .count();
+        assert!(failures > 0);
+    }
+
+    #[test]
+    fn out_of_range_words_are_rejected() {
+        assert!(matches!(Hamming15_11::default().encode(1 << 11), Err(EccError::FailedToEncode)));
+        assert!(matches!(Hamming15_11::default().decode(1 << 15), Err(EccError::FailedToDecode)));
+    }
+
+    #[test]
+    fn bit_buffer_with_error_in_every_codeword() {
+        let code = Hamming31_26::default();
+        let message: [u8; 37] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x3B));
+        let bits = message.len() * 8;
+        let mut encoded = [0u8; 48];
+
+        let written = code.encode_bits(&message, bits, &mut encoded).unwrap();
+        assert_eq!(written, bits.div_ceil(26) * 31);
+
+        for word in 0..written / 31 {
+            let bit = word * 31 + word % 31;
+            encoded[bit / 8] ^= 0x80 >> (bit % 8);
+        }
+
+        let mut decoded = [0u8; 39];
+        assert_eq!(code.decode_bits(&encoded, written, &mut decoded).unwrap(), written / 31 * 26);
+        assert_eq!(decoded[..37], message);
+        assert!(decoded[37..].iter().all(|&byte| byte == 0));
+    }
+
+    #[test]
+    fn bit_buffer_sizes_are_checked() {
+        let code = Hamming15_11::default();
+        let mut out = [0u8; 2];
+        assert!(matches!(code.encode_bits(&[0u8; 2], 16, &mut out), Err(EccError::FailedToEncode)));
+        assert!(matches!(code.decode_bits(&[0u8; 2], 14, &mut out), Err(EccError::FailedToDecode)));
+    }
+}
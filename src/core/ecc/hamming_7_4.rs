@@ -1,5 +1,6 @@
 use super::*;
 
+#[derive(Default)]
 pub struct Hamming74;
 
 impl ErrorCorrectionCode for Hamming74 {
@@ -65,11 +66,36 @@ impl ErrorCorrectionCode for Hamming74 {
     }
 }
 
+impl SoftDecisionCode for Hamming74 {
+    /// Maximum likelihood decoding: picks the codeword with the largest
+    /// correlation with `llrs`, bits go from P1 to D4
+    fn decode_soft(&self, llrs: &[i8]) -> Result<Self::Input, EccError> {
+        if llrs.len() != 7 {
+            return Err(EccError::FailedToDecode);
+        }
+
+        let mut best = (i32::MIN, 0);
+        for data in 0u8..=0b1111 {
+            let codeword = self.encode(data)?;
+            let correlation: i32 = llrs.iter()
+                .enumerate()
+                .map(|(i, &llr)| if codeword >> (6 - i) & 1 == 0 { llr as i32 } else { -(llr as i32) })
+                .sum();
+            if correlation > best.0 {
+                best = (correlation, data);
+            }
+        }
+
+        Ok(best.1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Hamming74;
     use super::EccError;
-    use crate::core::ecc::ErrorCorrectionCode;
+    use crate::core::ecc::{ErrorCorrectionCode, SoftDecisionCode};
+    use crate::test_purpose::{channel_llr, XorShift64};
 
     #[test]
     fn all_4bit_values_roundtrip() {
@@ -116,4 +142,40 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn soft_decoding_corrects_unreliable_bits() {
+        let ecc = Hamming74;
+        let enc = ecc.encode(0b1010).unwrap();
+
+        // Две ошибки: жёсткое декодирование ошибается, мягкое — нет
+        let llrs: [i8; 7] = core::array::from_fn(|i| {
+            let llr = if enc >> (6 - i) & 1 == 0 { 60 } else { -60 };
+            if i == 1 || i == 4 { -llr / 6 } else { llr }
+        });
+        let hard = llrs.iter().fold(0u8, |acc, &llr| (acc << 1) | (llr < 0) as u8);
+
+        assert_ne!(ecc.decode(hard).unwrap(), 0b1010);
+        assert_eq!(ecc.decode_soft(&llrs).unwrap(), 0b1010);
+        assert!(matches!(ecc.decode_soft(&llrs[..6]), Err(EccError::FailedToDecode)));
+    }
+
+    #[test]
+    fn soft_decoding_lowers_ber() {
+        let ecc = Hamming74;
+        let mut rng = XorShift64(0x7E57_0000_0000_0074);
+        let (mut hard_errors, mut soft_errors) = (0, 0);
+
+        for _ in 0..4000 {
+            let val = (rng.next_u64() & 0xF) as u8;
+            let enc = ecc.encode(val).unwrap();
+            let llrs: [i8; 7] = core::array::from_fn(|i| channel_llr(enc >> (6 - i) & 1 == 1, &mut rng, 20));
+            let hard = llrs.iter().fold(0u8, |acc, &llr| (acc << 1) | (llr < 0) as u8);
+
+            hard_errors += (ecc.decode(hard).unwrap() ^ val).count_ones();
+            soft_errors += (ecc.decode_soft(&llrs).unwrap() ^ val).count_ones();
+        }
+
+        assert!(soft_errors * 3 < hard_errors * 2, "hard: {hard_errors}, soft: {soft_errors}");
+    }
 }
@@ -0,0 +1,82 @@
+use super::*;
+use super::hamming_7_4::Hamming74;
+
+/// Extended Hamming(8,4): *Hamming(7,4)* with the eighth bit holding parity
+/// of the whole codeword. Corrects one error and detects two (SECDED)
+#[derive(Default)]
+pub struct Hamming84;
+
+impl ErrorCorrectionCode for Hamming84 {
+    type Input = u8;  // Только нижние 4 бита используются
+    type Output = u8; // P0 P1 P2 D1 P3 D2 D3 D4
+
+    fn encode(&self, data: Self::Input) -> Result<Self::Output, EccError> {
+        let encoded = Hamming74.encode(data)?;
+        let parity = (encoded.count_ones() & 1) as u8;
+
+        Ok((parity << 7) | encoded)
+    }
+
+    fn decode(&self, data: Self::Output) -> Result<Self::Input, EccError> {
+        let inner = data & 0x7F;
+        let decoded = Hamming74.decode(inner)?;
+
+        // Нечётная общая чётность — одна ошибка (возможно, в самом бите чётности),
+        // чётная при ненулевом синдроме — две ошибки
+        let parity_failed = data.count_ones() & 1 == 1;
+        let syndrome_failed = Hamming74.encode(decoded)? != inner;
+        if syndrome_failed && !parity_failed {
+            return Err(EccError::FailedToDecode);
+        }
+
+        Ok(decoded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Hamming84;
+    use crate::core::ecc::{EccError, ErrorCorrectionCode};
+
+    #[test]
+    fn all_4bit_values_roundtrip() {
+        let ecc = Hamming84;
+        for val in 0u8..=0b1111 {
+            let enc = ecc.encode(val).unwrap();
+            assert_eq!(enc.count_ones() % 2, 0, "odd weight codeword {:08b}", enc);
+            assert_eq!(ecc.decode(enc).unwrap(), val);
+        }
+    }
+
+    #[test]
+    fn single_bit_errors_are_corrected() {
+        let ecc = Hamming84;
+        for val in 0u8..=0b1111 {
+            let enc = ecc.encode(val).unwrap();
+            for bit in 0..8 {
+                assert_eq!(
+                    ecc.decode(enc ^ (1 << bit)).unwrap(), val,
+                    "failed to correct bit {} in codeword {:08b}",
+                    bit, enc
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn double_bit_errors_are_detected() {
+        let ecc = Hamming84;
+        for val in 0u8..=0b1111 {
+            let enc = ecc.encode(val).unwrap();
+            for b1 in 0..8 {
+                for b2 in (b1 + 1)..8 {
+                    assert!(
+                        matches!(ecc.decode(enc ^ (1 << b1) ^ (1 << b2)), Err(EccError::FailedToDecode)),
+                        "двойная ошибка не обнаружена: bits {}+{} in {:08b}",
+                        b1, b2, enc
+                    );
+                }
+            }
+        }
+    }
+}
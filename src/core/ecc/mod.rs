@@ -1,10 +1,19 @@
+pub(crate) mod bits;
+pub mod convolutional;
+pub mod golay;
+pub mod hamming;
 pub mod hamming_7_4;
+pub mod hamming_8_4;
+pub mod no_ecc;
+pub mod reed_solomon;
 pub mod repetition_code;
 
 #[derive(Debug)]
 pub enum EccError {
     FailedToEncode,
-    FailedToDecode
+    FailedToDecode,
+    /// Parameters of the code are not supported
+    InvalidParameters,
 }
 
 pub trait ErrorCorrectionCode {
@@ -15,3 +24,10 @@ pub trait ErrorCorrectionCode {
     fn decode(&self, data: Self::Output) -> Result<Self::Input, EccError>;
 }
 
+/// Code which decodes soft decisions of a receiver
+pub trait SoftDecisionCode: ErrorCorrectionCode {
+    /// Decodes codeword given as log-likelihood ratios `ln(P(0) / P(1))`
+    /// of its bits in the order they are sent: positive values mean `0`,
+    /// zero means nothing is known about the bit
+    fn decode_soft(&self, llrs: &[i8]) -> Result<Self::Input, EccError>;
+}
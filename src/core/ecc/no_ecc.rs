@@ -0,0 +1,18 @@
+use super::*;
+
+/// `NoEcc` passes bytes through unchanged, for channels without error correction
+#[derive(Default)]
+pub struct NoEcc;
+
+impl ErrorCorrectionCode for NoEcc {
+    type Input = u8;
+    type Output = u8;
+
+    fn encode(&self, data: Self::Input) -> Result<Self::Output, EccError> {
+        Ok(data)
+    }
+
+    fn decode(&self, data: Self::Output) -> Result<Self::Input, EccError> {
+        Ok(data)
+    }
+}
@@ -0,0 +1,439 @@
+//! Reed-Solomon codes RS(N, K) over GF(2^8)
+//!
+//! A codeword is `data || parity` with `N - K` parity symbols, the first byte
+//! being the coefficient of the highest degree. The generator polynomial is
+//! `g(x) = (x - β^FCR)(x - β^(FCR + 1))...(x - β^(FCR + N - K - 1))`, where
+//! `β = α^PRIM`. Decoding uses Berlekamp-Massey, Chien search and Forney
+//! algorithm and corrects `e` errors and `f` erasures while `2e + f <= N - K`.
+
+use super::*;
+
+/// Field and root parameters of a code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RsParams {
+    /// Primitive polynomial of GF(2^8), including `x^8`
+    pub poly: u16,
+    /// Power of `β` of the first root of the generator polynomial
+    pub fcr: u8,
+    /// Power of `α` taken as `β`, must be coprime with 255
+    pub prim: u8,
+}
+
+impl RsParams {
+    /// `x^8 + x^4 + x^3 + x^2 + 1`, roots `α^0, α^1, ...`, as in QR codes and DVB
+    pub const CONVENTIONAL: Self = Self { poly: 0x11D, fcr: 0, prim: 1 };
+
+    /// `x^8 + x^7 + x^2 + x + 1`, roots `α^(11 * (128 - E))...` for a code
+    /// correcting `E` errors, the generator polynomial is symmetric.
+    ///
+    /// Field and roots are those of CCSDS 131.0-B, but symbols stay in the
+    /// conventional basis: the dual basis transformation of the standard is
+    /// not applied, so codewords are not interoperable with CCSDS ones
+    pub const fn symmetric(parity: usize) -> Self {
+        Self { poly: 0x187, fcr: (128 - parity / 2) as u8, prim: 11 }
+    }
+}
+
+impl Default for RsParams {
+    fn default() -> Self {
+        Self::CONVENTIONAL
+    }
+}
+
+/// GF(2^8) with log and antilog tables
+struct Gf256 {
+    exp: [u8; 255],
+    log: [u8; 256],
+}
+
+impl Gf256 {
+    fn new(poly: u16) -> Self {
+        let mut exp = [0u8; 255];
+        let mut log = [0u8; 256];
+        let mut value = 1u16;
+        for (i, power) in exp.iter_mut().enumerate() {
+            *power = value as u8;
+            log[value as usize] = i as u8;
+            value <<= 1;
+            if value & 0x100 != 0 {
+                value ^= poly;
+            }
+        }
+        Self { exp, log }
+    }
+
+    /// Returns `α^power`
+    #[inline]
+    fn alpha(&self, power: usize) -> u8 {
+        self.exp[power % 255]
+    }
+
+    #[inline]
+    fn mul(&self, a: u8, b: u8) -> u8 {
+        if a == 0 || b == 0 {
+            return 0;
+        }
+        self.alpha(self.log[a as usize] as usize + self.log[b as usize] as usize)
+    }
+
+    #[inline]
+    fn div(&self, a: u8, b: u8) -> u8 {
+        if a == 0 {
+            return 0;
+        }
+        self.alpha(self.log[a as usize] as usize + 255 - self.log[b as usize] as usize)
+    }
+
+    /// Returns `poly(x)`, coefficients go from the lowest degree
+    fn eval(&self, poly: &[u8], x: u8) -> u8 {
+        poly.iter().rev().fold(0, |acc, &coefficient| self.mul(acc, x) ^ coefficient)
+    }
+}
+
+/// RS(N, K) code: `K` data symbols and `N - K` parity symbols, `N <= 255`
+pub struct ReedSolomon<const N: usize, const K: usize> {
+    field: Gf256,
+    params: RsParams,
+    /// Coefficients of `g(x)` from `x^(N - K - 1)` down to `x^0`, the leading one is omitted
+    generator: [u8; N],
+}
+
+/// RS(255, 223), correcting 16 errors
+pub type Rs255_223 = ReedSolomon<255, 223>;
+
+impl<const N: usize, const K: usize> ReedSolomon<N, K> {
+    /// Number of parity symbols
+    pub const PARITY: usize = N - K;
+
+    const VALID: () = assert!(K > 0 && K < N && N <= 255, "ReedSolomon: unsupported N or K");
+
+    /// Constructs code with given field and roots
+    pub fn new(params: RsParams) -> Self {
+        let () = Self::VALID;
+        let field = Gf256::new(params.poly);
+
+        // g(x), coefficients from the lowest degree
+        let mut poly = [0u8; 256];
+        poly[0] = 1;
+        for j in 0..Self::PARITY {
+            let root = field.alpha(params.prim as usize * (params.fcr as usize + j));
+            for i in (1..=j + 1).rev() {
+                poly[i] = poly[i - 1] ^ field.mul(poly[i], root);
+            }
+            poly[0] = field.mul(poly[0], root);
+        }
+
+        let mut generator = [0u8; N];
+        for (j, coefficient) in generator[..Self::PARITY].iter_mut().enumerate() {
+            *coefficient = poly[Self::PARITY - 1 - j];
+        }
+
+        Self { field, params, generator }
+    }
+
+    /// Constructs code with symmetric generator polynomial, see [`RsParams::symmetric`]
+    pub fn symmetric() -> Self {
+        Self::new(RsParams::symmetric(Self::PARITY))
+    }
+
+    /// Returns `β^power`
+    #[inline]
+    fn beta(&self, power: usize) -> u8 {
+        self.field.alpha(self.params.prim as usize * (power % 255))
+    }
+
+    /// Writes parity of `data` into `parity`
+    fn parity(&self, data: &[u8], parity: &mut [u8]) {
+        let generator = &self.generator[..Self::PARITY];
+        for &byte in data {
+            let feedback = byte ^ parity[0];
+            parity.copy_within(1.., 0);
+            parity[Self::PARITY - 1] = 0;
+            for (register, &coefficient) in parity.iter_mut().zip(generator) {
+                *register ^= self.field.mul(feedback, coefficient);
+            }
+        }
+    }
+
+    /// Corrects `codeword` in place, returns number of corrected symbols
+    ///
+    /// # Arguments
+    ///
+    /// * `codeword` - received codeword
+    /// * `erasures` - indices of symbols known to be unreliable
+    pub fn decode_in_place(&self, codeword: &mut [u8; N], erasures: &[usize]) -> Result<usize, EccError> {
+        let parity = Self::PARITY;
+        let field = &self.field;
+        if erasures.len() > parity || erasures.iter().any(|&position| position >= N) {
+            return Err(EccError::FailedToDecode);
+        }
+
+        let mut syndromes = [0u8; 255];
+        for (j, syndrome) in syndromes[..parity].iter_mut().enumerate() {
+            let root = self.beta(self.params.fcr as usize + j);
+            *syndrome = codeword.iter().fold(0, |acc, &symbol| field.mul(acc, root) ^ symbol);
+        }
+        if syndromes[..parity].iter().all(|&syndrome| syndrome == 0) {
+            return Ok(0);
+        }
+
+        // Локатор стираний, с него начинается Berlekamp-Massey
+        let mut lambda = [0u8; 256];
+        lambda[0] = 1;
+        for (count, &position) in erasures.iter().enumerate() {
+            let x = self.beta(N - 1 - position);
+            for i in (1..=count + 1).rev() {
+                lambda[i] ^= field.mul(x, lambda[i - 1]);
+            }
+        }
+
+        let erased = erasures.len();
+        let mut b = lambda;
+        let mut length = erased;
+        for r in erased + 1..=parity {
+            let discrepancy = (0..r)
+                .map(|i| field.mul(lambda[i], syndromes[r - 1 - i]))
+                .fold(0, |acc, term| acc ^ term);
+
+            if discrepancy == 0 {
+                b.copy_within(..255, 1);
+                b[0] = 0;
+                continue;
+            }
+
+            let mut next = lambda;
+            for i in 1..256 {
+                next[i] ^= field.mul(discrepancy, b[i - 1]);
+            }
+
+            if 2 * length < r + erased {
+                length = r + erased - length;
+                for (b, &lambda) in b.iter_mut().zip(lambda.iter()) {
+                    *b = field.div(lambda, discrepancy);
+                }
+            } else {
+                b.copy_within(..255, 1);
+                b[0] = 0;
+            }
+            lambda = next;
+        }
+
+        let degree = lambda.iter().rposition(|&coefficient| coefficient != 0).unwrap_or(0);
+        if degree == 0 || degree > parity {
+            return Err(EccError::FailedToDecode);
+        }
+
+        // Chien search: корни Λ(x) — обратные локаторы ошибок
+        let mut roots = [0usize; 255];
+        let mut found = 0;
+        for power in 0..255 {
+            if field.eval(&lambda[..=degree], self.beta(255 - power)) == 0 {
+                if power >= N || found == degree {
+                    return Err(EccError::FailedToDecode);
+                }
+                roots[found] = power;
+                found += 1;
+            }
+        }
+        if found != degree {
+            return Err(EccError::FailedToDecode);
+        }
+
+        // Ω(x) = S(x)Λ(x) mod x^(N - K)
+        let mut omega = [0u8; 255];
+        for (i, coefficient) in omega[..parity].iter_mut().enumerate() {
+            *coefficient = (0..=i.min(degree))
+                .map(|j| field.mul(lambda[j], syndromes[i - j]))
+                .fold(0, |acc, term| acc ^ term);
+        }
+
+        // Λ'(x): в характеристике 2 остаются только нечётные степени
+        let mut derivative = [0u8; 256];
+        for i in (1..=degree).step_by(2) {
+            derivative[i - 1] = lambda[i];
+        }
+
+        for &power in &roots[..found] {
+            let x_inv = self.beta(255 - power);
+            let numerator = field.mul(
+                field.eval(&omega[..parity], x_inv),
+                self.beta(power * ((256 - self.params.fcr as usize) % 255)),
+            );
+            let denominator = field.eval(&derivative[..degree], x_inv);
+            if denominator == 0 {
+                return Err(EccError::FailedToDecode);
+            }
+            codeword[N - 1 - power] ^= field.div(numerator, denominator);
+        }
+
+        Ok(found)
+    }
+}
+
+impl<const N: usize, const K: usize> Default for ReedSolomon<N, K> {
+    fn default() -> Self {
+        Self::new(RsParams::default())
+    }
+}
+
+impl<const N: usize, const K: usize> ErrorCorrectionCode for ReedSolomon<N, K> {
+    type Input = [u8; K];
+    type Output = [u8; N];
+
+    fn encode(&self, data: Self::Input) -> Result<Self::Output, EccError> {
+        let mut codeword = [0u8; N];
+        let (head, parity) = codeword.split_at_mut(K);
+        head.copy_from_slice(&data);
+        self.parity(&data, parity);
+        Ok(codeword)
+    }
+
+    fn decode(&self, data: Self::Output) -> Result<Self::Input, EccError> {
+        let mut codeword = data;
+        self.decode_in_place(&mut codeword, &[])?;
+
+        let mut decoded = [0u8; K];
+        decoded.copy_from_slice(&codeword[..K]);
+        Ok(decoded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_purpose::XorShift64;
+
+    /// Returns `count` distinct positions below `n`
+    fn positions(rng: &mut XorShift64, n: usize, count: usize) -> [usize; 255] {
+        let mut positions = [0usize; 255];
+        let mut found = 0;
+        while found < count {
+            let position = (rng.next_u64() % n as u64) as usize;
+            if !positions[..found].contains(&position) {
+                positions[found] = position;
+                found += 1;
+            }
+        }
+        positions
+    }
+
+    fn random_codeword(code: &Rs255_223, rng: &mut XorShift64) -> [u8; 255] {
+        let data: [u8; 223] = core::array::from_fn(|_| rng.next_u64() as u8);
+        code.encode(data).unwrap()
+    }
+
+    #[test]
+    fn qr_code_hello_world() {
+        // QR 1-M, "HELLO WORLD": 16 информационных и 10 проверочных байт
+        let code = ReedSolomon::<26, 16>::default();
+        let data = [32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17];
+        let codeword = code.encode(data).unwrap();
+
+        assert_eq!(codeword[16..], [196, 35, 39, 119, 235, 215, 231, 226, 93, 23]);
+        assert_eq!(code.decode(codeword).unwrap(), data);
+    }
+
+    #[test]
+    fn symmetric_generator_polynomial() {
+        // CCSDS 131.0-B, g(x) в показателях α; многочлен симметричен
+        const CCSDS_POLY: [u8; 33] = [
+            0, 249, 59, 66, 4, 43, 126, 251, 97, 30, 3, 213, 50, 66, 170, 5, 24,
+            5, 170, 66, 50, 213, 3, 30, 97, 251, 126, 43, 4, 66, 59, 249, 0,
+        ];
+        let code = Rs255_223::symmetric();
+
+        assert_eq!(code.field.exp[..10], [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x87, 0x89]);
+        for (j, &coefficient) in code.generator[..32].iter().enumerate() {
+            assert_eq!(code.field.log[coefficient as usize], CCSDS_POLY[j + 1]);
+        }
+    }
+
+    #[test]
+    fn up_to_16_errors_are_corrected() {
+        let code = Rs255_223::symmetric();
+        let mut rng = XorShift64(0x1234_5678_9ABC_DEF1);
+
+        for errors in 0..=16 {
+            let codeword = random_codeword(&code, &mut rng);
+            let mut received = codeword;
+            for &position in &positions(&mut rng, 255, errors)[..errors] {
+                received[position] ^= (rng.next_u64() % 255 + 1) as u8;
+            }
+
+            assert_eq!(code.decode_in_place(&mut received, &[]).unwrap(), errors);
+            assert_eq!(received, codeword);
+        }
+    }
+
+    #[test]
+    fn burst_is_corrected() {
+        let code = Rs255_223::symmetric();
+        let mut rng = XorShift64(0x0F0F_0F0F_1234_4321);
+        let codeword = random_codeword(&code, &mut rng);
+
+        let mut received = codeword;
+        received[100..116].fill(0);
+        code.decode_in_place(&mut received, &[]).unwrap();
+        assert_eq!(received, codeword);
+    }
+
+    #[test]
+    fn erasures_and_errors_are_corrected() {
+        let code = Rs255_223::symmetric();
+        let mut rng = XorShift64(0xDEAD_BEEF_0BAD_F00D);
+
+        // 2e + f <= 32
+        for (errors, erased) in [(0, 32), (5, 22), (10, 12), (15, 2)] {
+            let codeword = random_codeword(&code, &mut rng);
+            let positions = positions(&mut rng, 255, errors + erased);
+            let mut received = codeword;
+            for &position in &positions[..errors + erased] {
+                received[position] ^= (rng.next_u64() % 255 + 1) as u8;
+            }
+
+            code.decode_in_place(&mut received, &positions[errors..errors + erased]).unwrap();
+            assert_eq!(received, codeword, "{errors} errors, {erased} erasures");
+        }
+    }
+
+    #[test]
+    fn too_many_errors_never_yield_invalid_codeword() {
+        let code = Rs255_223::symmetric();
+        let mut rng = XorShift64(0x5555_AAAA_5555_AAAA);
+
+        for _ in 0..64 {
+            let codeword = random_codeword(&code, &mut rng);
+            let mut received = codeword;
+            for &position in &positions(&mut rng, 255, 17)[..17] {
+                received[position] ^= (rng.next_u64() % 255 + 1) as u8;
+            }
+
+            // Либо отказ, либо исправление в другое кодовое слово не дальше 16 символов
+            let corrupted = received;
+            if let Ok(corrected) = code.decode_in_place(&mut received, &[]) {
+                let data: [u8; 223] = received[..223].try_into().unwrap();
+                let changed = corrupted.iter().zip(&received).filter(|(a, b)| a != b).count();
+                assert!(corrected <= 16 && changed <= corrected);
+                assert_eq!(code.encode(data).unwrap(), received);
+                assert_ne!(received, codeword);
+            }
+        }
+        assert!(code.decode_in_place(&mut [0u8; 255], &[0; 33]).is_err());
+    }
+
+    #[test]
+    fn shortened_code() {
+        let code = ReedSolomon::<16, 8>::default();
+        let data = *b"\x00radio\xFF\x01";
+        let codeword = code.encode(data).unwrap();
+
+        for first in 0..16 {
+            for second in first + 1..16 {
+                let mut received = codeword;
+                received[first] ^= 0x5A;
+                received[second] = !received[second];
+                assert_eq!(code.decode(received).unwrap(), data);
+            }
+        }
+    }
+}
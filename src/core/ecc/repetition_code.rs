@@ -1,6 +1,6 @@
 use super::*;
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct RepetitionCode;
 
 impl ErrorCorrectionCode for RepetitionCode {
@@ -26,9 +26,26 @@ impl ErrorCorrectionCode for RepetitionCode {
     }
 }
 
+impl SoftDecisionCode for RepetitionCode {
+    /// Sums LLRs of the three copies of every bit, bits of each byte go from the most significant
+    fn decode_soft(&self, llrs: &[i8]) -> Result<Self::Input, EccError> {
+        if llrs.len() != 24 {
+            return Err(EccError::FailedToDecode);
+        }
+
+        let mut decoded = 0u8;
+        for bit in 0..8 {
+            let sum: i32 = (0..3).map(|copy| llrs[copy * 8 + bit] as i32).sum();
+            decoded = (decoded << 1) | (sum < 0) as u8;
+        }
+        Ok(decoded)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_purpose::{channel_llr, XorShift64};
 
     #[test]
     fn test_repetition_code_encode_decode() {
@@ -68,4 +85,27 @@ mod tests {
         let decoded = codec.decode(corrupted);
         assert!(decoded.is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn soft_decoding_lowers_ber() {
+        let codec = RepetitionCode;
+        let mut rng = XorShift64(0x7E57_0000_0000_0003);
+        let (mut hard_errors, mut soft_errors) = (0, 0);
+
+        for _ in 0..2000 {
+            let original = rng.next_u64() as u8;
+            let llrs: [i8; 24] = core::array::from_fn(|i| channel_llr(original >> (7 - i % 8) & 1 == 1, &mut rng, 24));
+
+            let mut hard = [0u8; 3];
+            for (i, &llr) in llrs.iter().enumerate() {
+                hard[i / 8] |= ((llr < 0) as u8) << (7 - i % 8);
+            }
+            // Жёсткое решение — побитовое большинство из трёх копий
+            let [a, b, c] = hard;
+            hard_errors += (((a & b) | (a & c) | (b & c)) ^ original).count_ones();
+            soft_errors += (codec.decode_soft(&llrs).unwrap() ^ original).count_ones();
+        }
+
+        assert!(soft_errors * 3 < hard_errors * 2, "hard: {hard_errors}, soft: {soft_errors}");
+    }
+}
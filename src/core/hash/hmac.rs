@@ -0,0 +1,126 @@
+//! [HMAC](https://datatracker.ietf.org/doc/html/rfc2104.html), used with Streebog as
+//! [HMAC_GOSTR3411_2012](https://datatracker.ietf.org/doc/html/rfc7836.html#section-4.1)
+
+use core::mem;
+
+use crate::core::hash::HashFunction;
+use crate::core::zeroize::Zeroize;
+
+const IPAD: u8 = 0x36;
+const OPAD: u8 = 0x5c;
+
+/// `HMAC` over any `HashFunction`, states keyed with the padded key are wiped on drop
+#[derive(Clone)]
+pub struct Hmac<H: Zeroize> {
+    inner: H,
+    outer: H,
+}
+
+impl<H> Hmac<H>
+where
+    H: HashFunction + Default + Zeroize,
+    H::Output: Zeroize,
+{
+    /// Constructs `HMAC` keyed with `key`.
+    /// Keys longer than the hash block are hashed first.
+    pub fn new(key: &[u8]) -> Self {
+        let mut block = H::ZERO_BLOCK;
+        let block_len = block.as_ref().len();
+        if key.len() > block_len {
+            let mut hashed = H::digest(key);
+            block.as_mut()[..hashed.as_ref().len()].copy_from_slice(hashed.as_ref());
+            hashed.zeroize();
+        } else {
+            block.as_mut()[..key.len()].copy_from_slice(key);
+        }
+
+        let mut inner = H::default();
+        let mut outer = H::default();
+        let mut pad = H::ZERO_BLOCK;
+
+        pad.as_mut().iter_mut().zip(block.as_ref()).for_each(|(p, k)| *p = k ^ IPAD);
+        inner.update(pad.as_ref());
+        pad.as_mut().iter_mut().zip(block.as_ref()).for_each(|(p, k)| *p = k ^ OPAD);
+        outer.update(pad.as_ref());
+
+        block.as_mut().iter_mut().for_each(Zeroize::zeroize);
+        pad.as_mut().iter_mut().for_each(Zeroize::zeroize);
+        Self { inner, outer }
+    }
+
+    /// Absorbs next part of the message
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    /// Returns `H((K xor opad) || H((K xor ipad) || text))`
+    pub fn finalize(mut self) -> H::Output {
+        let mut digest = mem::take(&mut self.inner).finalize();
+        self.outer.update(digest.as_ref());
+        digest.zeroize();
+        mem::take(&mut self.outer).finalize()
+    }
+
+    /// Returns `HMAC` of `data` keyed with `key`
+    pub fn mac(key: &[u8], data: &[u8]) -> H::Output {
+        let mut hmac = Self::new(key);
+        hmac.update(data);
+        hmac.finalize()
+    }
+}
+
+impl<H: Zeroize> Drop for Hmac<H> {
+    fn drop(&mut self) {
+        self.inner.zeroize();
+        self.outer.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::hash::streebog::streebog::{Streebog256, Streebog512};
+    use crate::test_purpose::*;
+
+    #[test]
+    fn hmac_streebog256_r_50_1_113() {
+        assert_eq!(Hmac::<Streebog256>::mac(&HMAC_KEY, &HMAC_DATA), HMAC_STREEBOG256);
+    }
+
+    #[test]
+    fn hmac_streebog512_r_50_1_113() {
+        assert_eq!(Hmac::<Streebog512>::mac(&HMAC_KEY, &HMAC_DATA), HMAC_STREEBOG512);
+    }
+
+    #[test]
+    fn incremental_update() {
+        let mut hmac = Hmac::<Streebog256>::new(&HMAC_KEY);
+        hmac.update(&HMAC_DATA[..5]);
+        hmac.update(&HMAC_DATA[5..]);
+        assert_eq!(hmac.finalize(), HMAC_STREEBOG256);
+    }
+
+    #[test]
+    fn drop_clears_keyed_states() {
+        use core::mem::{size_of, MaybeUninit};
+
+        let mut slot = MaybeUninit::new(Hmac::<Streebog256>::new(&HMAC_KEY));
+        // SAFETY: the slot is initialized; after the drop its storage is still
+        // owned by the slot and holds only integers without padding
+        unsafe {
+            slot.assume_init_drop();
+            let bytes = core::slice::from_raw_parts(slot.as_ptr().cast::<u8>(), size_of::<Hmac<Streebog256>>());
+            assert!(bytes.iter().all(|&byte| byte == 0));
+        }
+    }
+
+    #[test]
+    fn long_key_is_hashed() {
+        let long_key = [0xAB; 100];
+        let hashed_key = Streebog256::digest(&long_key);
+        assert_eq!(
+            Hmac::<Streebog256>::mac(&long_key, &HMAC_DATA),
+            Hmac::<Streebog256>::mac(&hashed_key, &HMAC_DATA)
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! [KDF_GOSTR3411_2012_256](https://datatracker.ietf.org/doc/html/rfc7836.html#section-4.4), R 50.1.113-2016
+//!
+//! Derives keys from one master secret, e.g. a separate `Magma` key for every
+//! link and direction:
+//!
+//! ```
+//! use secure_radio::core::cipher::magma::magma::MagmaBuilder;
+//! use secure_radio::core::hash::kdf;
+//!
+//! let master = [0x42u8; 32];
+//! let uplink = kdf::derive_magma_key(&master, b"uplink", &[0, 0, 0, 7]);
+//! let magma = MagmaBuilder::new().set_key(uplink).try_build();
+//! assert!(magma.is_ok());
+//! ```
+
+use crate::core::cipher::magma::key::Key;
+use crate::core::hash::hmac::Hmac;
+use crate::core::hash::streebog::streebog::Streebog256;
+
+/// Returns `HMAC_GOSTR3411_2012_256(key, 0x01 || label || 0x00 || seed || 0x01 || 0x00)`
+///
+/// # Arguments
+///
+/// * `key` - master secret
+/// * `label` - purpose of the derived key
+/// * `seed` - unique value, e.g. link identifier or nonce
+pub fn kdf_gostr3411_2012_256(key: &[u8], label: &[u8], seed: &[u8]) -> [u8; 32] {
+    let mut hmac = Hmac::<Streebog256>::new(key);
+    hmac.update(&[0x01]);
+    hmac.update(label);
+    hmac.update(&[0x00]);
+    hmac.update(seed);
+    hmac.update(&[0x01, 0x00]);
+    hmac.finalize()
+}
+
+/// Returns `Magma` key derived with `KDF_GOSTR3411_2012_256`
+pub fn derive_magma_key(key: &[u8], label: &[u8], seed: &[u8]) -> Key {
+    Key::from(kdf_gostr3411_2012_256(key, label, seed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_purpose::*;
+
+    #[test]
+    fn kdf_r_50_1_113() {
+        assert_eq!(kdf_gostr3411_2012_256(&HMAC_KEY, &KDF_LABEL, &KDF_SEED), KDF_256);
+    }
+
+    #[test]
+    fn derived_magma_key_is_accepted() {
+        use crate::core::cipher::magma::magma::MagmaBuilder;
+
+        let key: [u32; 8] = derive_magma_key(&HMAC_KEY, &KDF_LABEL, &KDF_SEED).into();
+        assert_eq!(key[0], 0xa1aa5f7d);
+        assert_eq!(key[7], 0xd4922ed9);
+
+        let magma = MagmaBuilder::default().set_key(key).build();
+        assert_eq!(magma.key, key);
+    }
+
+    #[test]
+    fn labels_and_seeds_separate_keys() {
+        let up = kdf_gostr3411_2012_256(&HMAC_KEY, b"uplink", &[1]);
+        let down = kdf_gostr3411_2012_256(&HMAC_KEY, b"downlink", &[1]);
+        let other_link = kdf_gostr3411_2012_256(&HMAC_KEY, b"uplink", &[2]);
+
+        assert_ne!(up, down);
+        assert_ne!(up, other_link);
+    }
+}
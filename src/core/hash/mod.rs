@@ -0,0 +1,29 @@
+pub mod hmac;
+pub mod kdf;
+pub mod streebog;
+
+/// Hash function with incremental interface
+pub trait HashFunction {
+    type Output: AsRef<[u8]>;
+    /// Input block, used by `HMAC` to pad the key
+    type Block: AsRef<[u8]> + AsMut<[u8]> + Copy;
+
+    /// Input block filled with zeros
+    const ZERO_BLOCK: Self::Block;
+
+    /// Absorbs next part of the message
+    fn update(&mut self, data: &[u8]);
+
+    /// Returns hash code of the whole message
+    fn finalize(self) -> Self::Output;
+
+    /// Returns hash code of `data`
+    fn digest(data: &[u8]) -> Self::Output
+    where
+        Self: Default + Sized,
+    {
+        let mut hasher = Self::default();
+        hasher.update(data);
+        hasher.finalize()
+    }
+}
@@ -0,0 +1,3 @@
+pub mod consts;
+#[allow(clippy::module_inception)]
+pub mod streebog;
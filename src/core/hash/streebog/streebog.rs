@@ -0,0 +1,308 @@
+//! [Streebog, GOST R 34.11-2012](https://datatracker.ietf.org/doc/html/rfc6986.html)
+//!
+//! Messages and hash codes are byte strings in memory order: the first byte
+//! of the message is the least significant byte of the first block, as in
+//! most implementations.
+
+use crate::core::hash::streebog::consts::*;
+use crate::core::hash::HashFunction;
+use crate::core::zeroize::Zeroize;
+
+/// `V_512` as little-endian words
+type Vector = [u64; 8];
+
+const BLOCK_SIZE: usize = 64;
+
+/// Compression state shared by both hash code lengths
+#[derive(Clone)]
+pub struct StreebogCore {
+    h: Vector,
+    n: Vector,
+    sigma: Vector,
+    buffer: [u8; BLOCK_SIZE],
+    buffer_len: usize,
+}
+
+impl StreebogCore {
+    /// Constructs state with the initializing vector `iv`
+    pub fn new(iv: Vector) -> Self {
+        Self {
+            h: iv,
+            n: [0; 8],
+            sigma: [0; 8],
+            buffer: [0; BLOCK_SIZE],
+            buffer_len: 0,
+        }
+    }
+
+    pub fn update(&mut self, mut data: &[u8]) {
+        if self.buffer_len > 0 {
+            let take = (BLOCK_SIZE - self.buffer_len).min(data.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&data[..take]);
+            self.buffer_len += take;
+            data = &data[take..];
+
+            if self.buffer_len < BLOCK_SIZE {
+                return;
+            }
+            let block = Self::vector_from_bytes(&self.buffer);
+            self.process_block(&block, BLOCK_SIZE as u64 * 8);
+            self.buffer_len = 0;
+        }
+
+        let mut chunks = data.chunks_exact(BLOCK_SIZE);
+        for chunk in &mut chunks {
+            self.process_block(&Self::vector_from_bytes(chunk), BLOCK_SIZE as u64 * 8);
+        }
+
+        let rest = chunks.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffer_len = rest.len();
+    }
+
+    /// [Stage 3](https://datatracker.ietf.org/doc/html/rfc6986.html#section-8.3), returns `h`
+    pub fn finalize(mut self) -> [u8; BLOCK_SIZE] {
+        // m = 0^(511 - |M|) || 1 || M
+        self.buffer[self.buffer_len..].fill(0);
+        self.buffer[self.buffer_len] = 0x01;
+        let block = Self::vector_from_bytes(&self.buffer);
+        self.process_block(&block, self.buffer_len as u64 * 8);
+
+        let (n, sigma) = (self.n, self.sigma);
+        self.h = Self::compress(&self.h, &[0; 8], &n);
+        self.h = Self::compress(&self.h, &[0; 8], &sigma);
+
+        let mut res = [0u8; BLOCK_SIZE];
+        for (chunk, word) in res.chunks_exact_mut(8).zip(self.h.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        res
+    }
+
+    /// [Stage 2](https://datatracker.ietf.org/doc/html/rfc6986.html#section-8.2) for one block of `bits` message bits
+    fn process_block(&mut self, m: &Vector, bits: u64) {
+        self.h = Self::compress(&self.h, &self.n, m);
+        Self::add(&mut self.n, &[bits, 0, 0, 0, 0, 0, 0, 0]);
+        Self::add(&mut self.sigma, m);
+    }
+
+    fn vector_from_bytes(bytes: &[u8]) -> Vector {
+        let mut v = [0u64; 8];
+        for (word, chunk) in v.iter_mut().zip(bytes.chunks_exact(8)) {
+            *word = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        v
+    }
+
+    /// Addition in `Z_(2^512)`
+    fn add(a: &mut Vector, b: &Vector) {
+        let mut carry = false;
+        for (a, &b) in a.iter_mut().zip(b.iter()) {
+            let (sum, c_1) = a.overflowing_add(b);
+            let (sum, c_2) = sum.overflowing_add(carry as u64);
+            *a = sum;
+            carry = c_1 | c_2;
+        }
+    }
+
+    /// [Transformation](https://datatracker.ietf.org/doc/html/rfc6986.html#section-5)
+    ///
+    /// `X[k]: V_512 -> V_512`
+    #[inline]
+    fn transformation_x(k: &Vector, a: &Vector) -> Vector {
+        let mut res = *a;
+        res.iter_mut().zip(k.iter()).for_each(|(r, k)| *r ^= k);
+        res
+    }
+
+    /// [Transformations](https://datatracker.ietf.org/doc/html/rfc6986.html#section-5)
+    ///
+    /// `LPS: V_512 -> V_512`, where `P` transposes the `8 x 8` byte matrix
+    #[inline]
+    fn transformation_lps(a: &Vector) -> Vector {
+        let mut res = [0u64; 8];
+        for (i, word) in res.iter_mut().enumerate() {
+            // S and P: byte `i` of every word forms the word `i`
+            let mut b: u64 = 0;
+            for (j, &a) in a.iter().enumerate() {
+                let byte = PI[((a >> (8 * i)) & 0xFF) as usize] as u64;
+                b |= byte << (8 * j);
+            }
+            *word = Self::transformation_l(b);
+        }
+        res
+    }
+
+    /// [Transformation](https://datatracker.ietf.org/doc/html/rfc6986.html#section-5)
+    ///
+    /// `l: V_64 -> V_64`
+    #[inline]
+    fn transformation_l(b: u64) -> u64 {
+        A.iter()
+            .enumerate()
+            .fold(0, |acc, (i, &row)| acc ^ (row & ((b >> (63 - i)) & 1).wrapping_neg()))
+    }
+
+    /// [Compression function](https://datatracker.ietf.org/doc/html/rfc6986.html#section-7)
+    ///
+    /// `g_N(h, m) = E(LPS(h xor N), m) xor h xor m`
+    fn compress(h: &Vector, n: &Vector, m: &Vector) -> Vector {
+        let mut k = Self::transformation_lps(&Self::transformation_x(h, n));
+        let mut state = *m;
+
+        for c in C.iter() {
+            state = Self::transformation_lps(&Self::transformation_x(&k, &state));
+            k = Self::transformation_lps(&Self::transformation_x(&k, c));
+        }
+        state = Self::transformation_x(&k, &state);
+
+        Self::transformation_x(h, &Self::transformation_x(m, &state))
+    }
+}
+
+impl Zeroize for StreebogCore {
+    fn zeroize(&mut self) {
+        self.h.zeroize();
+        self.n.zeroize();
+        self.sigma.zeroize();
+        self.buffer.zeroize();
+        self.buffer_len.zeroize();
+    }
+}
+
+/// The state may be derived from a key, e.g. in `HMAC`
+impl Drop for StreebogCore {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// Streebog with 512-bit hash code
+#[derive(Clone)]
+pub struct Streebog512 {
+    core: StreebogCore,
+}
+
+impl Default for Streebog512 {
+    fn default() -> Self {
+        Self { core: StreebogCore::new([0; 8]) }
+    }
+}
+
+impl HashFunction for Streebog512 {
+    type Output = [u8; 64];
+    type Block = [u8; BLOCK_SIZE];
+
+    const ZERO_BLOCK: Self::Block = [0; BLOCK_SIZE];
+
+    fn update(&mut self, data: &[u8]) {
+        self.core.update(data)
+    }
+
+    fn finalize(self) -> Self::Output {
+        self.core.finalize()
+    }
+}
+
+impl Zeroize for Streebog512 {
+    fn zeroize(&mut self) {
+        self.core.zeroize();
+    }
+}
+
+/// Streebog with 256-bit hash code
+#[derive(Clone)]
+pub struct Streebog256 {
+    core: StreebogCore,
+}
+
+impl Default for Streebog256 {
+    fn default() -> Self {
+        // IV = (00000001)^64
+        Self { core: StreebogCore::new([0x0101_0101_0101_0101; 8]) }
+    }
+}
+
+impl HashFunction for Streebog256 {
+    type Output = [u8; 32];
+    type Block = [u8; BLOCK_SIZE];
+
+    const ZERO_BLOCK: Self::Block = [0; BLOCK_SIZE];
+
+    fn update(&mut self, data: &[u8]) {
+        self.core.update(data)
+    }
+
+    /// Returns `MSB_256(h)`
+    fn finalize(self) -> Self::Output {
+        let h = self.core.finalize();
+        let mut res = [0u8; 32];
+        res.copy_from_slice(&h[32..]);
+        res
+    }
+}
+
+impl Zeroize for Streebog256 {
+    fn zeroize(&mut self) {
+        self.core.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod streebog_test {
+    use super::*;
+    use crate::test_purpose::*;
+
+    #[test]
+    fn streebog512_rfc6986_m1() {
+        assert_eq!(Streebog512::digest(&STREEBOG_M1), STREEBOG512_M1);
+    }
+
+    #[test]
+    fn streebog256_rfc6986_m1() {
+        assert_eq!(Streebog256::digest(&STREEBOG_M1), STREEBOG256_M1);
+    }
+
+    #[test]
+    fn streebog512_rfc6986_m2() {
+        assert_eq!(Streebog512::digest(&STREEBOG_M2), STREEBOG512_M2);
+    }
+
+    #[test]
+    fn streebog256_rfc6986_m2() {
+        assert_eq!(Streebog256::digest(&STREEBOG_M2), STREEBOG256_M2);
+    }
+
+    #[test]
+    fn incremental_update() {
+        for split in [0, 1, 7, 63, 64, 65, 71, 72] {
+            let mut hasher = Streebog512::default();
+            hasher.update(&STREEBOG_M2[..split]);
+            hasher.update(&STREEBOG_M2[split..]);
+            assert_eq!(hasher.finalize(), STREEBOG512_M2, "split at {}", split);
+        }
+
+        let mut hasher = Streebog256::default();
+        STREEBOG_M2.iter().for_each(|b| hasher.update(&[*b]));
+        assert_eq!(hasher.finalize(), STREEBOG256_M2);
+    }
+
+    #[test]
+    fn zeroize_clears_state() {
+        let mut hasher = Streebog256::default();
+        hasher.update(&STREEBOG_M2);
+        hasher.zeroize();
+
+        assert_eq!(hasher.core.h, [0; 8]);
+        assert_eq!(hasher.core.n, [0; 8]);
+        assert_eq!(hasher.core.sigma, [0; 8]);
+        assert_eq!(hasher.core.buffer, [0; BLOCK_SIZE]);
+        assert_eq!(hasher.core.buffer_len, 0);
+    }
+
+    #[test]
+    fn different_messages_differ() {
+        assert_ne!(Streebog256::digest(&[]), Streebog256::digest(&[0]));
+        assert_ne!(Streebog256::digest(&[0; 64]), Streebog256::digest(&[0; 65]));
+    }
+}
@@ -0,0 +1,375 @@
+//! Interleavers spread burst errors of the channel over many codewords
+//!
+//! [`Interleaved`] puts an interleaver between the error correction code
+//! and the channel of any `GeneralCipher` with a byte array frame: a burst
+//! of up to the interleaver depth consecutive damaged units hits every
+//! codeword at most once.
+
+use crate::core::cipher::{Cipher, CipherError};
+use crate::core::ecc::bits::{read_bits, write_bits};
+use crate::core::ecc::{EccError, ErrorCorrectionCode};
+use crate::core::{GeneralCipher, GeneralCipherError};
+
+/// Unit moved by an interleaver
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Granularity {
+    #[default]
+    Bit,
+    Byte,
+}
+
+impl Granularity {
+    /// Returns number of units in `bytes` bytes
+    fn units(self, bytes: usize) -> usize {
+        match self {
+            Self::Bit => bytes * 8,
+            Self::Byte => bytes,
+        }
+    }
+
+    fn copy(self, src: &[u8], from: usize, dst: &mut [u8], to: usize) {
+        match self {
+            Self::Bit => write_bits(dst, to, 1, read_bits(src, from, 1)),
+            Self::Byte => dst[to] = src[from],
+        }
+    }
+}
+
+pub trait Interleaver {
+    fn granularity(&self) -> Granularity;
+
+    /// Returns `true` if a frame of `units` units can be interleaved
+    fn supports(&self, units: usize) -> bool;
+
+    /// Returns position of unit `index` after interleaving a frame of `units` units
+    fn position(&self, index: usize, units: usize) -> usize;
+
+    /// Writes interleaved `src` into `dst` of the same length
+    fn interleave(&self, src: &[u8], dst: &mut [u8]) -> Result<(), EccError> {
+        let units = self.checked_units(src, dst)?;
+        let granularity = self.granularity();
+        for index in 0..units {
+            granularity.copy(src, index, dst, self.position(index, units));
+        }
+        Ok(())
+    }
+
+    /// Writes deinterleaved `src` into `dst` of the same length
+    fn deinterleave(&self, src: &[u8], dst: &mut [u8]) -> Result<(), EccError> {
+        let units = self.checked_units(src, dst)?;
+        let granularity = self.granularity();
+        for index in 0..units {
+            granularity.copy(src, self.position(index, units), dst, index);
+        }
+        Ok(())
+    }
+
+    /// Returns number of units in `src`, if it is supported and fits into `dst`
+    fn checked_units(&self, src: &[u8], dst: &[u8]) -> Result<usize, EccError> {
+        let units = self.granularity().units(src.len());
+        if dst.len() != src.len() || !self.supports(units) {
+            return Err(EccError::InvalidParameters);
+        }
+        Ok(units)
+    }
+}
+
+/// Block interleaver: units are written into a `rows × columns` table row
+/// by row and read column by column. Its depth is `rows`, so a codeword
+/// should take no more than `columns` units
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockInterleaver {
+    rows: usize,
+    columns: usize,
+    granularity: Granularity,
+}
+
+impl BlockInterleaver {
+    /// Constructs interleaver, frames are processed in tables of `rows × columns` units
+    pub fn new(rows: usize, columns: usize, granularity: Granularity) -> Result<Self, EccError> {
+        match rows.checked_mul(columns) {
+            Some(table) if table != 0 => Ok(Self { rows, columns, granularity }),
+            _ => Err(EccError::InvalidParameters),
+        }
+    }
+}
+
+impl Interleaver for BlockInterleaver {
+    fn granularity(&self) -> Granularity {
+        self.granularity
+    }
+
+    fn supports(&self, units: usize) -> bool {
+        units.is_multiple_of(self.rows * self.columns)
+    }
+
+    fn position(&self, index: usize, _units: usize) -> usize {
+        let table = self.rows * self.columns;
+        let (row, column) = (index % table / self.columns, index % self.columns);
+        index - index % table + column * self.rows + row
+    }
+}
+
+/// Intra-frame helical interleaver: units are dealt to `branches` branches
+/// in turn and branch `j` shifts its units by `j * delay` cells, wrapping
+/// around the frame. It spreads bursts like a convolutional (Forney)
+/// interleaver, but keeps no delay line state between frames, so every
+/// frame is interleaved on its own. Its depth is `branches`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HelicalInterleaver {
+    branches: usize,
+    delay: usize,
+    granularity: Granularity,
+}
+
+impl HelicalInterleaver {
+    /// Constructs interleaver, frames must be a whole number of `branches` units
+    pub fn new(branches: usize, delay: usize, granularity: Granularity) -> Result<Self, EccError> {
+        if branches == 0 || branches.checked_mul(branches).and_then(|n| n.checked_mul(delay)).is_none() {
+            return Err(EccError::InvalidParameters);
+        }
+        Ok(Self { branches, delay, granularity })
+    }
+}
+
+impl Interleaver for HelicalInterleaver {
+    fn granularity(&self) -> Granularity {
+        self.granularity
+    }
+
+    fn supports(&self, units: usize) -> bool {
+        units != 0 && units.is_multiple_of(self.branches)
+    }
+
+    fn position(&self, index: usize, units: usize) -> usize {
+        let branch = index % self.branches;
+        (index + branch * self.delay * self.branches % units) % units
+    }
+}
+
+/// `Interleaved` interleaves frames of `G` before they are sent
+pub struct Interleaved<G, I> {
+    inner: G,
+    interleaver: I,
+}
+
+impl<G, I, const FRAME: usize> Interleaved<G, I>
+where
+    G: GeneralCipher<Output = [u8; FRAME]>,
+    I: Interleaver,
+{
+    /// Constructs suite, `interleaver` must support frames of `G`
+    pub fn new(inner: G, interleaver: I) -> Result<Self, EccError> {
+        if !interleaver.supports(interleaver.granularity().units(FRAME)) {
+            return Err(EccError::InvalidParameters);
+        }
+        Ok(Self { inner, interleaver })
+    }
+}
+
+impl<G, I> Interleaved<G, I> {
+    /// Returns suite which frames are interleaved
+    pub fn inner(&self) -> &G {
+        &self.inner
+    }
+
+    /// Returns suite which frames are interleaved, e.g. to `wipe` its key
+    pub fn inner_mut(&mut self) -> &mut G {
+        &mut self.inner
+    }
+
+    /// Returns suite which frames are interleaved
+    pub fn into_inner(self) -> G {
+        self.inner
+    }
+}
+
+impl<G: Cipher, I> Cipher for Interleaved<G, I> {
+    type Input = G::Input;
+    type Output = G::Output;
+
+    fn encrypt(&self, data: Self::Input) -> Result<Self::Output, CipherError> {
+        self.inner.encrypt(data)
+    }
+
+    fn decrypt(&self, data: Self::Output) -> Result<Self::Input, CipherError> {
+        self.inner.decrypt(data)
+    }
+}
+
+impl<G: ErrorCorrectionCode, I> ErrorCorrectionCode for Interleaved<G, I> {
+    type Input = G::Input;
+    type Output = G::Output;
+
+    fn encode(&self, data: Self::Input) -> Result<Self::Output, EccError> {
+        self.inner.encode(data)
+    }
+
+    fn decode(&self, data: Self::Output) -> Result<Self::Input, EccError> {
+        self.inner.decode(data)
+    }
+}
+
+impl<G, I, B, const FRAME: usize> GeneralCipher for Interleaved<G, I>
+where
+    G: GeneralCipher<Input = B, Output = [u8; FRAME]>,
+    I: Interleaver,
+{
+    type Input = B;
+    type Output = [u8; FRAME];
+
+    fn general_encrypt(&self, data: B) -> Result<[u8; FRAME], GeneralCipherError> {
+        let frame = self.inner.general_encrypt(data)?;
+        let mut interleaved = [0u8; FRAME];
+        self.interleaver.interleave(&frame, &mut interleaved)
+            .map_err(|_| GeneralCipherError::ECCEncodeError)?;
+        Ok(interleaved)
+    }
+
+    fn general_decrypt(&self, data: [u8; FRAME]) -> Result<B, GeneralCipherError> {
+        let mut frame = [0u8; FRAME];
+        self.interleaver.deinterleave(&data, &mut frame)
+            .map_err(|_| GeneralCipherError::ECCDecodeError)?;
+        self.inner.general_decrypt(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::default_ciphers::magma_hamming::MagmaHamming;
+    use crate::core::default_ciphers::magma_repetition::MagmaRepetition;
+    use crate::test_purpose::*;
+
+    /// Damages `len` units of `frame` from `start`
+    fn burst<const N: usize>(frame: [u8; N], granularity: Granularity, start: usize, len: usize) -> [u8; N] {
+        let mut damaged = frame;
+        for unit in start..start + len {
+            match granularity {
+                Granularity::Bit => damaged[unit / 8] ^= 0x80 >> (unit % 8),
+                Granularity::Byte => damaged[unit] ^= 0xFF,
+            }
+        }
+        damaged
+    }
+
+    /// Checks that every burst of `len` units is corrected
+    fn bursts_are_corrected<G, const N: usize>(suite: &G, granularity: Granularity, len: usize) -> bool
+    where
+        G: GeneralCipher<Input = u64, Output = [u8; N]>,
+    {
+        let frame = suite.general_encrypt(PLAINTEXT1).unwrap();
+        (0..=granularity.units(N) - len).all(|start| {
+            matches!(suite.general_decrypt(burst(frame, granularity, start, len)), Ok(PLAINTEXT1))
+        })
+    }
+
+    #[test]
+    fn interleaving_is_a_permutation() {
+        let src: [u8; 24] = core::array::from_fn(|i| (i as u8).wrapping_mul(0x9D) ^ 0x3C);
+        let interleavers: [&dyn Interleaver; 4] = [
+            &BlockInterleaver::new(8, 3, Granularity::Byte).unwrap(),
+            &BlockInterleaver::new(4, 12, Granularity::Bit).unwrap(),
+            &HelicalInterleaver::new(6, 1, Granularity::Byte).unwrap(),
+            &HelicalInterleaver::new(8, 3, Granularity::Bit).unwrap(),
+        ];
+
+        for interleaver in interleavers {
+            let (mut interleaved, mut restored) = ([0u8; 24], [0u8; 24]);
+            interleaver.interleave(&src, &mut interleaved).unwrap();
+            interleaver.deinterleave(&interleaved, &mut restored).unwrap();
+
+            assert_ne!(interleaved, src);
+            assert_eq!(restored, src);
+        }
+    }
+
+    #[test]
+    fn block_table_is_read_by_columns() {
+        let interleaver = BlockInterleaver::new(2, 3, Granularity::Byte).unwrap();
+        let mut interleaved = [0u8; 6];
+        interleaver.interleave(&[1, 2, 3, 4, 5, 6], &mut interleaved).unwrap();
+        assert_eq!(interleaved, [1, 4, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    fn bit_bursts_in_hamming_frames() {
+        let plain = MagmaHamming::new(CIPHER_KEY);
+        assert!(!bursts_are_corrected(&plain, Granularity::Bit, 9));
+
+        // 16 кодовых слов по 8 бит: глубина — число строк
+        let block = Interleaved::new(MagmaHamming::new(CIPHER_KEY), BlockInterleaver::new(16, 8, Granularity::Bit).unwrap()).unwrap();
+        assert!(bursts_are_corrected(&block, Granularity::Bit, 16));
+
+        let helical = Interleaved::new(
+            MagmaHamming::new(CIPHER_KEY),
+            HelicalInterleaver::new(8, 1, Granularity::Bit).unwrap(),
+        ).unwrap();
+        assert!(bursts_are_corrected(&helical, Granularity::Bit, 8));
+    }
+
+    #[test]
+    fn byte_bursts_in_repetition_frames() {
+        let plain = MagmaRepetition::new(CIPHER_KEY);
+        assert!(!bursts_are_corrected(&plain, Granularity::Byte, 2));
+
+        let block = Interleaved::new(MagmaRepetition::new(CIPHER_KEY), BlockInterleaver::new(8, 3, Granularity::Byte).unwrap()).unwrap();
+        assert!(bursts_are_corrected(&block, Granularity::Byte, 8));
+
+        let helical = Interleaved::new(
+            MagmaRepetition::new(CIPHER_KEY),
+            HelicalInterleaver::new(6, 1, Granularity::Byte).unwrap(),
+        ).unwrap();
+        assert!(bursts_are_corrected(&helical, Granularity::Byte, 6));
+    }
+
+    #[test]
+    fn messages_are_interleaved_frame_by_frame() {
+        let suite = Interleaved::new(MagmaHamming::new(CIPHER_KEY), BlockInterleaver::new(16, 8, Granularity::Bit).unwrap()).unwrap();
+        let message = *b"interleaved telemetry";
+        let mut encrypted = [0u8; 48];
+        let mut decrypted = [0u8; 21];
+
+        let written = suite.encrypt_into(&message, &mut encrypted).unwrap();
+        for frame in encrypted[..written].chunks_mut(16) {
+            frame[4] ^= 0xFF;
+            frame[5] ^= 0xFF;
+        }
+        assert_eq!(suite.decrypt_into(&encrypted[..written], &mut decrypted).unwrap(), 21);
+        assert_eq!(decrypted, message);
+    }
+
+    #[test]
+    fn inner_suite_can_be_wiped() {
+        let mut suite = Interleaved::new(MagmaHamming::new(CIPHER_KEY), BlockInterleaver::new(16, 8, Granularity::Bit).unwrap()).unwrap();
+
+        suite.inner_mut().wipe();
+        assert_eq!(suite.inner().cipher.key, [0u32; 8]);
+        assert_eq!(suite.into_inner().cipher.round_keys, [0u32; 32]);
+    }
+
+    #[test]
+    fn unsupported_frames_are_rejected() {
+        let block = Interleaved::new(MagmaHamming::new(CIPHER_KEY), BlockInterleaver::new(5, 3, Granularity::Byte).unwrap());
+        assert!(matches!(block, Err(EccError::InvalidParameters)));
+
+        let helical = Interleaved::new(MagmaRepetition::new(CIPHER_KEY), HelicalInterleaver::new(5, 1, Granularity::Byte).unwrap());
+        assert!(matches!(helical, Err(EccError::InvalidParameters)));
+    }
+
+    #[test]
+    fn invalid_parameters_and_buffers_are_rejected() {
+        assert!(matches!(BlockInterleaver::new(0, 3, Granularity::Byte), Err(EccError::InvalidParameters)));
+        assert!(matches!(BlockInterleaver::new(8, 0, Granularity::Bit), Err(EccError::InvalidParameters)));
+        assert!(matches!(BlockInterleaver::new(usize::MAX, 2, Granularity::Bit), Err(EccError::InvalidParameters)));
+        assert!(matches!(HelicalInterleaver::new(0, 1, Granularity::Byte), Err(EccError::InvalidParameters)));
+
+        let block = BlockInterleaver::new(8, 3, Granularity::Byte).unwrap();
+        let helical = HelicalInterleaver::new(8, 3, Granularity::Bit).unwrap();
+        let mut dst = [0u8; 26];
+        assert!(matches!(block.interleave(&[0u8; 26], &mut dst), Err(EccError::InvalidParameters)));
+        assert!(matches!(block.interleave(&[0u8; 24], &mut dst), Err(EccError::InvalidParameters)));
+        assert!(matches!(helical.deinterleave(&[0u8; 24], &mut dst[..23]), Err(EccError::InvalidParameters)));
+        assert!(matches!(helical.interleave(&[], &mut []), Err(EccError::InvalidParameters)));
+    }
+}
@@ -1,6 +1,13 @@
 pub mod cipher;
 pub mod default_ciphers;
 pub mod ecc;
+pub mod hash;
+pub mod interleave;
+pub mod pipeline;
+pub mod zeroize;
+
+use cipher::modes::{padding::Padding, Block};
+use pipeline::Codeword;
 
 #[derive(Debug)]
 pub enum GeneralCipherError {
@@ -8,6 +15,23 @@ pub enum GeneralCipherError {
     ECCEncodeError,
     CipherDecryptError,
     CipherEncryptError,
+    AuthenticationError,
+    /// Nonce is out of range of the mode or nonces are exhausted
+    InvalidNonce,
+    /// Cipher can not be built from the given key material
+    InvalidKey,
+    /// Output buffer can not hold the result
+    BufferTooSmall,
+    /// Encrypted message is not a whole number of frames
+    InvalidLength,
+    /// Padding of the decrypted message is malformed
+    InvalidPadding,
+}
+
+impl From<cipher::magma::magma::MagmaBuilderError> for GeneralCipherError {
+    fn from(_: cipher::magma::magma::MagmaBuilderError) -> Self {
+        Self::InvalidKey
+    }
 }
 
 pub trait GeneralCipher: cipher::Cipher + ecc::ErrorCorrectionCode { 
@@ -16,4 +40,103 @@ pub trait GeneralCipher: cipher::Cipher + ecc::ErrorCorrectionCode {
 
     fn general_encrypt(&self, data: <Self as GeneralCipher>::Input) -> Result<<Self as GeneralCipher>::Output, GeneralCipherError>;
     fn general_decrypt(&self, data: <Self as GeneralCipher>::Output) -> Result<<Self as GeneralCipher>::Input, GeneralCipherError>;
+
+    /// Returns length of `encrypt_into` output for a message of `plaintext_len` bytes.
+    /// The message is always padded, so at least one frame is produced
+    fn encrypted_len(plaintext_len: usize) -> usize
+    where
+        <Self as GeneralCipher>::Input: Block,
+        <Self as GeneralCipher>::Output: Codeword,
+    {
+        let blocks = plaintext_len / <Self as GeneralCipher>::Input::SIZE + 1;
+        blocks * <<Self as GeneralCipher>::Output as Codeword>::LEN
+    }
+
+    /// Returns largest length of a message recovered from `ciphertext_len` bytes
+    fn max_decrypted_len(ciphertext_len: usize) -> usize
+    where
+        <Self as GeneralCipher>::Input: Block,
+        <Self as GeneralCipher>::Output: Codeword,
+    {
+        let blocks = ciphertext_len / <<Self as GeneralCipher>::Output as Codeword>::LEN;
+        (blocks * <Self as GeneralCipher>::Input::SIZE).saturating_sub(1)
+    }
+
+    /// Encrypts and encodes `plaintext` of any length into `out`, returns number of written bytes.
+    ///
+    /// The message is padded with procedure 2 of GOST R 34.13-2015,
+    /// so its length is recovered on decryption.
+    ///
+    /// Frames are encrypted independently: even with an authenticated suite
+    /// nothing binds them to the message or to each other, so the message as
+    /// a whole has no integrity. Frames may be reordered, dropped or taken
+    /// from another message without `decrypt_into` noticing
+    fn encrypt_into(&self, plaintext: &[u8], out: &mut [u8]) -> Result<usize, GeneralCipherError>
+    where
+        <Self as GeneralCipher>::Input: Block,
+        <Self as GeneralCipher>::Output: Codeword,
+    {
+        let block_size = <Self as GeneralCipher>::Input::SIZE;
+        let frame_len = <<Self as GeneralCipher>::Output as Codeword>::LEN;
+        let len = Self::encrypted_len(plaintext.len());
+        if out.len() < len {
+            return Err(GeneralCipherError::BufferTooSmall);
+        }
+
+        let (out, _) = out.split_at_mut(len);
+        let (head, last_frame) = out.split_at_mut(len - frame_len);
+        let chunks = plaintext.chunks_exact(block_size);
+        let rest = chunks.remainder();
+
+        for (chunk, frame) in chunks.zip(head.chunks_exact_mut(frame_len)) {
+            self.general_encrypt(<Self as GeneralCipher>::Input::from_be_slice(chunk))?.write(frame);
+        }
+
+        let mut last = <<Self as GeneralCipher>::Input as Block>::Bytes::default();
+        last.as_mut()[..rest.len()].copy_from_slice(rest);
+        Padding::Bit
+            .pad::<<Self as GeneralCipher>::Input>(last.as_mut(), rest.len())
+            .map_err(|_| GeneralCipherError::CipherEncryptError)?;
+        self.general_encrypt(<Self as GeneralCipher>::Input::from_block_bytes(last))?.write(last_frame);
+
+        Ok(len)
+    }
+
+    /// Decodes and decrypts `data` produced by `encrypt_into` into `out`, returns length of the message.
+    /// `out` needs at most `max_decrypted_len(data.len())` bytes.
+    ///
+    /// Every frame is checked on its own, see `encrypt_into`
+    fn decrypt_into(&self, data: &[u8], out: &mut [u8]) -> Result<usize, GeneralCipherError>
+    where
+        <Self as GeneralCipher>::Input: Block,
+        <Self as GeneralCipher>::Output: Codeword,
+    {
+        let block_size = <Self as GeneralCipher>::Input::SIZE;
+        let frame_len = <<Self as GeneralCipher>::Output as Codeword>::LEN;
+        if data.is_empty() || !data.len().is_multiple_of(frame_len) {
+            return Err(GeneralCipherError::InvalidLength);
+        }
+
+        let (head, last_frame) = data.split_at(data.len() - frame_len);
+        let head_len = head.len() / frame_len * block_size;
+        if out.len() < head_len {
+            return Err(GeneralCipherError::BufferTooSmall);
+        }
+
+        for (frame, chunk) in head.chunks_exact(frame_len).zip(out.chunks_exact_mut(block_size)) {
+            let block = self.general_decrypt(<Self as GeneralCipher>::Output::read(frame))?;
+            chunk.copy_from_slice(block.to_block_bytes().as_ref());
+        }
+
+        let last = self.general_decrypt(<Self as GeneralCipher>::Output::read(last_frame))?.to_block_bytes();
+        let rest = Padding::Bit
+            .unpad::<<Self as GeneralCipher>::Input>(last.as_ref())
+            .map_err(|_| GeneralCipherError::InvalidPadding)?;
+
+        out.get_mut(head_len..head_len + rest)
+            .ok_or(GeneralCipherError::BufferTooSmall)?
+            .copy_from_slice(&last.as_ref()[..rest]);
+
+        Ok(head_len + rest)
+    }
 }
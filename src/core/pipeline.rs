@@ -0,0 +1,432 @@
+//! Generic `GeneralCipher` made of any block cipher and any error correction code
+//!
+//! The ciphertext block is cut into symbols by a [`SymbolAdapter`], every
+//! symbol is encoded separately and the codewords are written one after
+//! another into the frame:
+//!
+//! `block -> E(block) -> symbols -> codewords -> frame`
+
+use core::marker::PhantomData;
+
+use crate::core::cipher::kuznyechik::key::Key as KuznyechikKey;
+use crate::core::cipher::kuznyechik::kuznyechik::{Kuznyechik, KuznyechikBuilder};
+use crate::core::cipher::magma::key::Key as MagmaKey;
+use crate::core::cipher::magma::consts::SBOX;
+use crate::core::cipher::magma::magma::Magma;
+use crate::core::cipher::magma::param_set::ByteOrder;
+use crate::core::cipher::modes::Block;
+use crate::core::cipher::{Cipher, CipherError};
+use crate::core::ecc::bits::{read_bits, write_bits};
+use crate::core::ecc::{EccError, ErrorCorrectionCode};
+use crate::core::{GeneralCipher, GeneralCipherError};
+
+/// Describes how bytes of a cipher block map to input symbols of a code.
+/// Symbol `i` is made of bits `[i * BITS, (i + 1) * BITS)` of the block,
+/// counting from the most significant bit; bits past the end are zeros.
+pub trait SymbolAdapter {
+    type Symbol;
+
+    /// Number of bits in a symbol
+    const BITS: usize;
+
+    /// Returns `index`-th symbol of `bytes`
+    fn symbol(bytes: &[u8], index: usize) -> Self::Symbol;
+
+    /// Writes `index`-th symbol into zero-initialized `bytes`
+    fn put(bytes: &mut [u8], index: usize, symbol: Self::Symbol);
+}
+
+/// Every byte is one symbol
+pub struct Bytes;
+
+impl SymbolAdapter for Bytes {
+    type Symbol = u8;
+
+    const BITS: usize = 8;
+
+    #[inline]
+    fn symbol(bytes: &[u8], index: usize) -> u8 {
+        bytes[index]
+    }
+
+    #[inline]
+    fn put(bytes: &mut [u8], index: usize, symbol: u8) {
+        bytes[index] = symbol;
+    }
+}
+
+/// Every byte is two symbols, the high nibble goes first
+pub struct Nibbles;
+
+impl SymbolAdapter for Nibbles {
+    type Symbol = u8;
+
+    const BITS: usize = 4;
+
+    #[inline]
+    fn symbol(bytes: &[u8], index: usize) -> u8 {
+        let byte = bytes[index / 2];
+        if index.is_multiple_of(2) { byte >> 4 } else { byte & 0x0F }
+    }
+
+    #[inline]
+    fn put(bytes: &mut [u8], index: usize, symbol: u8) {
+        let shift = if index.is_multiple_of(2) { 4 } else { 0 };
+        bytes[index / 2] |= (symbol & 0x0F) << shift;
+    }
+}
+
+/// Every `N` bytes are one symbol, the last one is padded with zeros
+pub struct Chunks<const N: usize>;
+
+impl<const N: usize> SymbolAdapter for Chunks<N> {
+    type Symbol = [u8; N];
+
+    const BITS: usize = 8 * N;
+
+    #[inline]
+    fn symbol(bytes: &[u8], index: usize) -> [u8; N] {
+        let chunk = &bytes[index * N..bytes.len().min((index + 1) * N)];
+        let mut symbol = [0u8; N];
+        symbol[..chunk.len()].copy_from_slice(chunk);
+        symbol
+    }
+
+    #[inline]
+    fn put(bytes: &mut [u8], index: usize, symbol: [u8; N]) {
+        let chunk = &mut bytes[index * N..];
+        let len = chunk.len().min(N);
+        chunk[..len].copy_from_slice(&symbol[..len]);
+    }
+}
+
+/// Every `N <= 16` bits are one symbol, the last one is padded with zeros
+pub struct Bits<const N: usize>;
+
+impl<const N: usize> SymbolAdapter for Bits<N> {
+    type Symbol = u16;
+
+    const BITS: usize = {
+        assert!(N > 0 && N <= 16, "Bits: unsupported symbol size");
+        N
+    };
+
+    #[inline]
+    fn symbol(bytes: &[u8], index: usize) -> u16 {
+        read_bits(bytes, index * Self::BITS, Self::BITS) as u16
+    }
+
+    #[inline]
+    fn put(bytes: &mut [u8], index: usize, symbol: u16) {
+        let count = Self::BITS.min(bytes.len() * 8 - index * Self::BITS);
+        write_bits(bytes, index * Self::BITS, count, (symbol >> (Self::BITS - count)) as u64);
+    }
+}
+
+/// Codeword which is sent over the channel as `LEN` bytes
+pub trait Codeword: Sized {
+    const LEN: usize;
+
+    fn write(&self, out: &mut [u8]);
+    fn read(bytes: &[u8]) -> Self;
+}
+
+impl Codeword for u8 {
+    const LEN: usize = 1;
+
+    #[inline]
+    fn write(&self, out: &mut [u8]) {
+        out[0] = *self;
+    }
+
+    #[inline]
+    fn read(bytes: &[u8]) -> Self {
+        bytes[0]
+    }
+}
+
+impl<const N: usize> Codeword for [u8; N] {
+    const LEN: usize = N;
+
+    #[inline]
+    fn write(&self, out: &mut [u8]) {
+        out.copy_from_slice(self);
+    }
+
+    #[inline]
+    fn read(bytes: &[u8]) -> Self {
+        let mut codeword = [0u8; N];
+        codeword.copy_from_slice(bytes);
+        codeword
+    }
+}
+
+/// Encodes every symbol of `bytes` with `ecc`, codewords fill `frame` one after another
+pub(crate) fn encode_symbols<E, A>(ecc: &E, bytes: &[u8], frame: &mut [u8]) -> Result<(), GeneralCipherError>
+where
+    E: ErrorCorrectionCode<Input = A::Symbol>,
+    E::Output: Codeword,
+    A: SymbolAdapter,
+{
+    for (i, chunk) in frame.chunks_exact_mut(<E::Output as Codeword>::LEN).enumerate() {
+        ecc.encode(A::symbol(bytes, i))
+            .map_err(|_| GeneralCipherError::ECCEncodeError)?
+            .write(chunk);
+    }
+    Ok(())
+}
+
+/// Decodes codewords of `frame` with `ecc` and puts symbols into zero-initialized `bytes`
+pub(crate) fn decode_symbols<E, A>(ecc: &E, frame: &[u8], bytes: &mut [u8]) -> Result<(), GeneralCipherError>
+where
+    E: ErrorCorrectionCode<Input = A::Symbol>,
+    E::Output: Codeword,
+    A: SymbolAdapter,
+{
+    for (i, chunk) in frame.chunks_exact(<E::Output as Codeword>::LEN).enumerate() {
+        let symbol = ecc
+            .decode(E::Output::read(chunk))
+            .map_err(|_| GeneralCipherError::ECCDecodeError)?;
+        A::put(bytes, i, symbol);
+    }
+    Ok(())
+}
+
+/// `Pipeline` encrypts a block with `C`, then protects it with `E`.
+///
+/// `FRAME` is the length of the encoded block in bytes, it is checked
+/// at compile time against the block size, `A::BITS` and the codeword length.
+pub struct Pipeline<C, E, A, const FRAME: usize> {
+    pub(crate) cipher: C,
+    pub(crate) ecc: E,
+    adapter: PhantomData<A>,
+}
+
+impl<C, E, A, const FRAME: usize> Pipeline<C, E, A, FRAME> {
+    /// Constructs suite from a ready cipher and code
+    pub fn from_parts(cipher: C, ecc: E) -> Self {
+        Self { cipher, ecc, adapter: PhantomData }
+    }
+}
+
+impl<E: Default, A, const FRAME: usize> Pipeline<Magma, E, A, FRAME> {
+    /// Constructs suite with `key` given as `[u32; 8]` or `[u8; 32]`
+    pub fn new(key: impl Into<MagmaKey>) -> Self {
+        Self::from_parts(Magma::new(key.into().to_words(ByteOrder::Rfc8891), SBOX), E::default())
+    }
+
+    /// Overwrites key material with zeros, the suite must not be used afterwards.
+    /// Key material is also wiped when the suite is dropped
+    pub fn wipe(&mut self) {
+        self.cipher.wipe();
+    }
+}
+
+impl<E: Default, A, const FRAME: usize> Default for Pipeline<Magma, E, A, FRAME> {
+    fn default() -> Self {
+        Self::from_parts(Magma::new([0u32; 8], SBOX), E::default())
+    }
+}
+
+impl<E: Default, A, const FRAME: usize> Pipeline<Kuznyechik, E, A, FRAME> {
+    /// Constructs suite with `key` given as `[u8; 32]` or `[u128; 2]`
+    pub fn new(key: impl Into<KuznyechikKey>) -> Self {
+        Self::from_parts(KuznyechikBuilder::new().set_key(key).build(), E::default())
+    }
+
+    /// Overwrites key material with zeros, the suite must not be used afterwards.
+    /// Key material is also wiped when the suite is dropped
+    pub fn wipe(&mut self) {
+        self.cipher.wipe();
+    }
+}
+
+impl<E: Default, A, const FRAME: usize> Default for Pipeline<Kuznyechik, E, A, FRAME> {
+    fn default() -> Self {
+        Self::from_parts(KuznyechikBuilder::default().build(), E::default())
+    }
+}
+
+impl<C: Cipher, E, A, const FRAME: usize> Cipher for Pipeline<C, E, A, FRAME> {
+    type Input = C::Input;
+    type Output = C::Output;
+
+    fn encrypt(&self, data: Self::Input) -> Result<Self::Output, CipherError> {
+        self.cipher.encrypt(data)
+    }
+
+    fn decrypt(&self, data: Self::Output) -> Result<Self::Input, CipherError> {
+        self.cipher.decrypt(data)
+    }
+}
+
+impl<C, E: ErrorCorrectionCode, A, const FRAME: usize> ErrorCorrectionCode for Pipeline<C, E, A, FRAME> {
+    type Input = E::Input;
+    type Output = E::Output;
+
+    fn encode(&self, data: Self::Input) -> Result<Self::Output, EccError> {
+        self.ecc.encode(data)
+    }
+
+    fn decode(&self, data: Self::Output) -> Result<Self::Input, EccError> {
+        self.ecc.decode(data)
+    }
+}
+
+impl<C, E, A, B, const FRAME: usize> Pipeline<C, E, A, FRAME>
+where
+    B: Block,
+    C: Cipher<Input = B, Output = B>,
+    E: ErrorCorrectionCode<Input = A::Symbol>,
+    E::Output: Codeword,
+    A: SymbolAdapter,
+{
+    /// Number of symbols in a block
+    const SYMBOLS: usize = (B::SIZE * 8).div_ceil(A::BITS);
+
+    const FRAME_FITS: () = assert!(
+        Self::SYMBOLS * <E::Output as Codeword>::LEN == FRAME,
+        "Pipeline: FRAME does not match the block size and the code"
+    );
+}
+
+impl<C, E, A, B, const FRAME: usize> GeneralCipher for Pipeline<C, E, A, FRAME>
+where
+    B: Block,
+    C: Cipher<Input = B, Output = B>,
+    E: ErrorCorrectionCode<Input = A::Symbol>,
+    E::Output: Codeword,
+    A: SymbolAdapter,
+{
+    type Input = B;
+    type Output = [u8; FRAME];
+
+    fn general_encrypt(&self, data: B) -> Result<[u8; FRAME], GeneralCipherError> {
+        let () = Self::FRAME_FITS;
+
+        let ciphered = self.cipher
+            .encrypt(data)
+            .map_err(|_| GeneralCipherError::CipherEncryptError)?
+            .to_block_bytes();
+
+        let mut frame = [0u8; FRAME];
+        encode_symbols::<E, A>(&self.ecc, ciphered.as_ref(), &mut frame)?;
+
+        Ok(frame)
+    }
+
+    fn general_decrypt(&self, data: [u8; FRAME]) -> Result<B, GeneralCipherError> {
+        let () = Self::FRAME_FITS;
+
+        let mut ciphered = B::Bytes::default();
+        decode_symbols::<E, A>(&self.ecc, &data, ciphered.as_mut())?;
+
+        self.cipher
+            .decrypt(B::from_block_bytes(ciphered))
+            .map_err(|_| GeneralCipherError::CipherDecryptError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ecc::hamming_7_4::Hamming74;
+    use crate::core::ecc::repetition_code::RepetitionCode;
+    use crate::test_purpose::*;
+
+    #[test]
+    fn nibbles_roundtrip() {
+        let bytes = [0x12, 0xAB];
+        let mut joined = [0u8; 2];
+
+        for i in 0..4 {
+            Nibbles::put(&mut joined, i, Nibbles::symbol(&bytes, i));
+        }
+        assert_eq!([Nibbles::symbol(&bytes, 0), Nibbles::symbol(&bytes, 3)], [0x1, 0xB]);
+        assert_eq!(joined, bytes);
+    }
+
+    #[test]
+    fn bit_symbols_roundtrip() {
+        let bytes = [0x12, 0x34, 0x56, 0x78, 0x9A];
+        let mut joined = [0u8; 5];
+
+        for i in 0..4 {
+            Bits::<12>::put(&mut joined, i, Bits::<12>::symbol(&bytes, i));
+        }
+        assert_eq!([Bits::<12>::symbol(&bytes, 1), Bits::<12>::symbol(&bytes, 3)], [0x456, 0xA00]);
+        assert_eq!(joined, bytes);
+    }
+
+    #[test]
+    fn frame_is_made_of_codewords() {
+        let pipeline = Pipeline::<Magma, RepetitionCode, Bytes, 24>::new(CIPHER_KEY);
+        let frame = pipeline.general_encrypt(PLAINTEXT).unwrap();
+
+        for (chunk, byte) in frame.chunks(3).zip(CIPHERTEXT.to_be_bytes()) {
+            assert_eq!(chunk, [byte; 3]);
+        }
+    }
+
+    #[test]
+    fn ready_parts_are_accepted() {
+        use crate::core::cipher::magma::magma::MagmaBuilder;
+        let cipher = MagmaBuilder::new().set_key(CIPHER_KEY).build();
+        let pipeline: Pipeline<_, _, Nibbles, 16> = Pipeline::from_parts(cipher, Hamming74);
+        let mut frame = pipeline.general_encrypt(PLAINTEXT).unwrap();
+
+        frame[3] ^= 0x04;
+        assert_eq!(pipeline.general_decrypt(frame).unwrap(), PLAINTEXT);
+    }
+
+    #[test]
+    fn message_of_any_length_roundtrip() {
+        type Suite = Pipeline<Magma, Hamming74, Nibbles, 16>;
+        let cipher = Suite::new(CIPHER_KEY);
+        let message: [u8; 40] = core::array::from_fn(|i| i as u8 ^ 0x5A);
+
+        for len in 0..=message.len() {
+            let mut encrypted = [0u8; 6 * 16];
+            let written = cipher.encrypt_into(&message[..len], &mut encrypted).unwrap();
+            assert_eq!(written, Suite::encrypted_len(len));
+            assert_eq!(written, (len / 8 + 1) * 16);
+
+            let mut decrypted = [0u8; 40];
+            let read = cipher.decrypt_into(&encrypted[..written], &mut decrypted[..len]).unwrap();
+            assert_eq!(&decrypted[..read], &message[..len]);
+            assert!(Suite::max_decrypted_len(written) >= len);
+        }
+    }
+
+    #[test]
+    fn telemetry_message_with_errors() {
+        let cipher = Pipeline::<Magma, RepetitionCode, Bytes, 24>::new(CIPHER_KEY);
+        let message = *b"T=21.5C;P=1013hPa;V=3.30;ID=0042;OK!!";
+        let mut encrypted = [0u8; 5 * 24];
+        let written = cipher.encrypt_into(&message, &mut encrypted).unwrap();
+
+        for frame in encrypted[..written].chunks_mut(24) {
+            frame[7] ^= 0xFF;
+        }
+
+        let mut decrypted = [0u8; 37];
+        assert_eq!(cipher.decrypt_into(&encrypted[..written], &mut decrypted).unwrap(), 37);
+        assert_eq!(decrypted, message);
+    }
+
+    #[test]
+    fn invalid_buffers_are_rejected() {
+        let cipher = Pipeline::<Magma, Hamming74, Nibbles, 16>::new(CIPHER_KEY);
+        let mut encrypted = [0u8; 32];
+
+        assert!(matches!(cipher.encrypt_into(&[0u8; 8], &mut encrypted[..31]), Err(GeneralCipherError::BufferTooSmall)));
+        let written = cipher.encrypt_into(&[0u8; 8], &mut encrypted).unwrap();
+
+        let mut decrypted = [0u8; 8];
+        assert!(matches!(cipher.decrypt_into(&encrypted[..written], &mut decrypted[..7]), Err(GeneralCipherError::BufferTooSmall)));
+        assert!(matches!(cipher.decrypt_into(&encrypted[..written - 1], &mut decrypted), Err(GeneralCipherError::InvalidLength)));
+        assert!(matches!(cipher.decrypt_into(&[], &mut decrypted), Err(GeneralCipherError::InvalidLength)));
+        // The last frame carries the padding
+        assert!(matches!(cipher.decrypt_into(&encrypted[..16], &mut decrypted), Err(GeneralCipherError::InvalidPadding)));
+    }
+}
@@ -0,0 +1,54 @@
+//! Erasure of secret material
+//!
+//! Plain assignments to memory which is never read again may be removed by
+//! the optimizer, so values are overwritten with volatile writes followed by
+//! a compiler fence.
+
+use core::ptr;
+use core::sync::atomic::{compiler_fence, Ordering};
+
+/// Types which can be overwritten with zeros
+pub trait Zeroize {
+    fn zeroize(&mut self);
+}
+
+macro_rules! impl_zeroize {
+    ($($t:ty),*) => {
+        $(
+            impl Zeroize for $t {
+                #[inline]
+                fn zeroize(&mut self) {
+                    // SAFETY: `self` is a valid and aligned reference
+                    unsafe { ptr::write_volatile(self, 0) };
+                    compiler_fence(Ordering::SeqCst);
+                }
+            }
+        )*
+    };
+}
+
+impl_zeroize!(u8, u16, u32, u64, u128, usize);
+
+impl<T: Zeroize, const N: usize> Zeroize for [T; N] {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.iter_mut().for_each(Zeroize::zeroize);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arrays_are_cleared() {
+        let mut words = [0xDEAD_BEEFu32; 8];
+        let mut nested = [[0xA5u8; 16]; 4];
+
+        words.zeroize();
+        nested.zeroize();
+
+        assert_eq!(words, [0u32; 8]);
+        assert_eq!(nested, [[0u8; 16]; 4]);
+    }
+}
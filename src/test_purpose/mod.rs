@@ -21,6 +21,60 @@ pub const PLAINTEXT3: u64 = 0x4a98fb2e67a8024c_u64;
 /// Plaintext4, Page 35, Section: A.2
 pub const PLAINTEXT4: u64 = 0x8912409b17b57e41_u64;
 
+/// ECB ciphertext, Section: A.2.1
+pub const ECB_CIPHERTEXT: [u64; 4] = [
+    0x2b073f0494f372a0, 0xde70e715d3556e48, 0x11d8d9e9eacfbc1e, 0x7c68260996c67efb,
+];
+
+/// CTR initializing value, Section: A.2.2
+pub const CTR_IV: u32 = 0x12345678;
+/// CTR ciphertext, Section: A.2.2
+pub const CTR_CIPHERTEXT: [u64; 4] = [
+    0x4e98110c97b7b93c, 0x3e250d93d6e85d69, 0x136d868807b2dbef, 0x568eb680ab52a12d,
+];
+
+/// OFB initializing value, Section: A.2.3
+pub const OFB_IV: [u64; 2] = [0x1234567890abcdef, 0x234567890abcdef1];
+/// OFB ciphertext, Section: A.2.3
+pub const OFB_CIPHERTEXT: [u64; 4] = [
+    0xdb37e0e266903c83, 0x0d46644c1f9a089c, 0xa0f83062430e327e, 0xc824efb8bd4fdb05,
+];
+
+/// CBC initializing value, Section: A.2.4
+pub const CBC_IV: [u64; 3] = [0x1234567890abcdef, 0x234567890abcdef1, 0x34567890abcdef12];
+/// CBC ciphertext, Section: A.2.4
+pub const CBC_CIPHERTEXT: [u64; 4] = [
+    0x96d1b05eea683919, 0xaff76129abb937b9, 0x5058b4a1c4bc0019, 0x20b78b1a7cd7e667,
+];
+
+/// CFB initializing value, Section: A.2.5
+pub const CFB_IV: [u64; 2] = [0x1234567890abcdef, 0x234567890abcdef1];
+/// CFB ciphertext, Section: A.2.5
+pub const CFB_CIPHERTEXT: [u64; 4] = [
+    0xdb37e0e266903c83, 0x0d46644c1f9a089c, 0x24bdd2035315d38b, 0xbcc0321421075505,
+];
+
+/// MAC subkey K1, Section: A.2.6
+pub const MAC_K1: u64 = 0x5f459b3342521424;
+/// MAC subkey K2, Section: A.2.6
+pub const MAC_K2: u64 = 0xbe8b366684a42848;
+/// MAC of `PLAINTEXT1..4` with `s = 32`, Section: A.2.6
+pub const MAC_32: [u8; 4] = [0x15, 0x4e, 0x72, 0x10];
+
+/// Returns `PLAINTEXT1..4` as one big-endian message
+pub fn plaintext_bytes() -> [u8; 32] {
+    blocks_to_bytes(&[PLAINTEXT1, PLAINTEXT2, PLAINTEXT3, PLAINTEXT4])
+}
+
+/// Returns `blocks` as one big-endian message
+pub fn blocks_to_bytes(blocks: &[u64; 4]) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (chunk, block) in bytes.chunks_mut(8).zip(blocks.iter()) {
+        chunk.copy_from_slice(&block.to_be_bytes());
+    }
+    bytes
+}
+
 
 
 /// [Section A.1. Transformation t](https://datatracker.ietf.org/doc/html/rfc8891.html#section-a.1)
@@ -95,4 +149,356 @@ pub const PLAINTEXT: u64 = 0xfedcba9876543210_u64;
 /// [Section A.4. Test Encryption](https://datatracker.ietf.org/doc/html/rfc8891.html#section-a.4)
 pub const CIPHERTEXT: u64 = 0x4ee901e5c2d8ca3d_u64;
 
+/// [Appendix A. Test Vectors, 64-bit block](https://datatracker.ietf.org/doc/html/rfc9058.html#appendix-A)
+///
+/// The key is `CIPHER_KEY`
+pub const MGM_NONCE: u64 = 0x12def06b3c130a59;
+
+/// [Appendix A. Test Vectors, 64-bit block](https://datatracker.ietf.org/doc/html/rfc9058.html#appendix-A)
+pub const MGM_ASSOCIATED_DATA: [u8; 41] = [
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
+    0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0xea,
+];
+
+/// [Appendix A. Test Vectors, 64-bit block](https://datatracker.ietf.org/doc/html/rfc9058.html#appendix-A)
+pub const MGM_PLAINTEXT: [u8; 67] = [
+    0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x00,
+    0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xee, 0xff, 0x0a, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77,
+    0x99, 0xaa, 0xbb, 0xcc, 0xee, 0xff, 0x0a, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88,
+    0xaa, 0xbb, 0xcc, 0xee, 0xff, 0x0a, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99,
+    0xaa, 0xbb, 0xcc,
+];
+
+/// [Appendix A. Test Vectors, 64-bit block](https://datatracker.ietf.org/doc/html/rfc9058.html#appendix-A)
+pub const MGM_CIPHERTEXT: [u8; 67] = [
+    0xc7, 0x95, 0x06, 0x6c, 0x5f, 0x9e, 0xa0, 0x3b, 0x85, 0x11, 0x33, 0x42, 0x45, 0x91, 0x85, 0xae,
+    0x1f, 0x2e, 0x00, 0xd6, 0xbf, 0x2b, 0x78, 0x5d, 0x94, 0x04, 0x70, 0xb8, 0xbb, 0x9c, 0x8e, 0x7d,
+    0x9a, 0x5d, 0xd3, 0x73, 0x1f, 0x7d, 0xdc, 0x70, 0xec, 0x27, 0xcb, 0x0a, 0xce, 0x6f, 0xa5, 0x76,
+    0x70, 0xf6, 0x5c, 0x64, 0x6a, 0xbb, 0x75, 0xd5, 0x47, 0xaa, 0x37, 0xc3, 0xbc, 0xb5, 0xc3, 0x4e,
+    0x03, 0xbb, 0x9c,
+];
+
+/// [Appendix A. Test Vectors, 64-bit block](https://datatracker.ietf.org/doc/html/rfc9058.html#appendix-A)
+pub const MGM_TAG: [u8; 8] = [0xa7, 0x92, 0x80, 0x69, 0xaa, 0x10, 0xfd, 0x10];
+
+/// [Section 5.4. Key Schedule](https://datatracker.ietf.org/doc/html/rfc7801.html#section-5.4)
+pub const KUZNYECHIK_KEY: [u8; 32] = [
+    0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77,
+    0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef,
+];
+
+/// [Section 5.4. Key Schedule](https://datatracker.ietf.org/doc/html/rfc7801.html#section-5.4)
+pub const KUZNYECHIK_ROUND_KEYS: [u128; 10] = [
+    0x8899aabbccddeeff0011223344556677,
+    0xfedcba98765432100123456789abcdef,
+    0xdb31485315694343228d6aef8cc78c44,
+    0x3d4553d8e9cfec6815ebadc40a9ffd04,
+    0x57646468c44a5e28d3e59246f429f1ac,
+    0xbd079435165c6432b532e82834da581b,
+    0x51e640757e8745de705727265a0098b1,
+    0x5a7925017b9fdd3ed72a91a22286f984,
+    0xbb44e25378c73123a5f32f73cdb6e517,
+    0x72e9dd7416bcf45b755dbaa88e4a4043,
+];
+
+/// [Section 5.1. Transformation S](https://datatracker.ietf.org/doc/html/rfc7801.html#section-5.1)
+pub const KUZNYECHIK_TRANSFORMATION_S: [(u128, u128); 4] = [
+    (0xffeeddccbbaa99881122334455667700, 0xb66cd8887d38e8d77765aeea0c9a7efc),
+    (0xb66cd8887d38e8d77765aeea0c9a7efc, 0x559d8dd7bd06cbfe7e7b262523280d39),
+    (0x559d8dd7bd06cbfe7e7b262523280d39, 0x0c3322fed531e4630d80ef5c5a81c50b),
+    (0x0c3322fed531e4630d80ef5c5a81c50b, 0x23ae65633f842d29c5df529c13f5acda),
+];
+
+/// [Section 5.2. Transformation R](https://datatracker.ietf.org/doc/html/rfc7801.html#section-5.2)
+pub const KUZNYECHIK_TRANSFORMATION_R: [(u128, u128); 4] = [
+    (0x00000000000000000000000000000100, 0x94000000000000000000000000000001),
+    (0x94000000000000000000000000000001, 0xa5940000000000000000000000000000),
+    (0xa5940000000000000000000000000000, 0x64a59400000000000000000000000000),
+    (0x64a59400000000000000000000000000, 0x0d64a594000000000000000000000000),
+];
+
+/// [Section 5.3. Transformation L](https://datatracker.ietf.org/doc/html/rfc7801.html#section-5.3)
+pub const KUZNYECHIK_TRANSFORMATION_L: [(u128, u128); 4] = [
+    (0x64a59400000000000000000000000000, 0xd456584dd0e3e84cc3166e4b7fa2890d),
+    (0xd456584dd0e3e84cc3166e4b7fa2890d, 0x79d26221b87b584cd42fbc4ffea5de9a),
+    (0x79d26221b87b584cd42fbc4ffea5de9a, 0x0e93691a0cfc60408b7b68f66b513c13),
+    (0x0e93691a0cfc60408b7b68f66b513c13, 0xe6a8094fee0aa204fd97bcb0b44b8580),
+];
+
+/// [Section 5.5. Test Encryption](https://datatracker.ietf.org/doc/html/rfc7801.html#section-5.5)
+pub const KUZNYECHIK_PLAINTEXT: u128 = 0x1122334455667700ffeeddccbbaa9988;
+
+/// [Section 5.5. Test Encryption](https://datatracker.ietf.org/doc/html/rfc7801.html#section-5.5)
+pub const KUZNYECHIK_CIPHERTEXT: u128 = 0x7f679d90bebc24305a468d42b9d4edcd;
+
+/// Plaintext, GOST R 34.13-2015 Section: A.1
+pub const KUZNYECHIK_MODES_PLAINTEXT: [u128; 4] = [
+    0x1122334455667700ffeeddccbbaa9988,
+    0x00112233445566778899aabbcceeff0a,
+    0x112233445566778899aabbcceeff0a00,
+    0x2233445566778899aabbcceeff0a0011,
+];
+
+/// ECB ciphertext, GOST R 34.13-2015 Section: A.1.1
+pub const KUZNYECHIK_ECB_CIPHERTEXT: [u128; 4] = [
+    0x7f679d90bebc24305a468d42b9d4edcd,
+    0xb429912c6e0032f9285452d76718d08b,
+    0xf0ca33549d247ceef3f5a5313bd4b157,
+    0xd0b09ccde830b9eb3a02c4c5aa8ada98,
+];
+
+/// CTR initializing value, GOST R 34.13-2015 Section: A.1.2
+pub const KUZNYECHIK_CTR_IV: u64 = 0x1234567890abcef0;
+/// CTR ciphertext, GOST R 34.13-2015 Section: A.1.2
+pub const KUZNYECHIK_CTR_CIPHERTEXT: [u128; 4] = [
+    0xf195d8bec10ed1dbd57b5fa240bda1b8,
+    0x85eee733f6a13e5df33ce4b33c45dee4,
+    0xa5eae88be6356ed3d5e877f13564a3a5,
+    0xcb91fab1f20cbab6d1c6d15820bdba73,
+];
+
+/// MAC with `s = 64`, GOST R 34.13-2015 Section: A.1.6
+pub const KUZNYECHIK_MAC_64: [u8; 8] = [0x33, 0x6f, 0x4d, 0x29, 0x60, 0x59, 0xfb, 0xe3];
+
+/// Returns `blocks` as one big-endian message
+pub fn kuznyechik_blocks_to_bytes(blocks: &[u128; 4]) -> [u8; 64] {
+    let mut bytes = [0u8; 64];
+    for (chunk, block) in bytes.chunks_mut(16).zip(blocks.iter()) {
+        chunk.copy_from_slice(&block.to_be_bytes());
+    }
+    bytes
+}
+
+/// [Appendix A. Test Vectors, 128-bit block](https://datatracker.ietf.org/doc/html/rfc9058.html#appendix-A)
+///
+/// The key is `KUZNYECHIK_KEY`
+pub const KUZNYECHIK_MGM_NONCE: u128 = 0x1122334455667700ffeeddccbbaa9988;
+
+/// [Appendix A. Test Vectors, 128-bit block](https://datatracker.ietf.org/doc/html/rfc9058.html#appendix-A)
+pub const KUZNYECHIK_MGM_ASSOCIATED_DATA: [u8; 41] = [
+    0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+    0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03,
+    0xea, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+];
+
+/// [Appendix A. Test Vectors, 128-bit block](https://datatracker.ietf.org/doc/html/rfc9058.html#appendix-A)
+pub const KUZNYECHIK_MGM_PLAINTEXT: [u8; 67] = [
+    0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x00, 0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88,
+    0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xee, 0xff, 0x0a,
+    0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xee, 0xff, 0x0a, 0x00,
+    0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xee, 0xff, 0x0a, 0x00, 0x11,
+    0xaa, 0xbb, 0xcc,
+];
+
+/// [Appendix A. Test Vectors, 128-bit block](https://datatracker.ietf.org/doc/html/rfc9058.html#appendix-A)
+pub const KUZNYECHIK_MGM_CIPHERTEXT: [u8; 67] = [
+    0xa9, 0x75, 0x7b, 0x81, 0x47, 0x95, 0x6e, 0x90, 0x55, 0xb8, 0xa3, 0x3d, 0xe8, 0x9f, 0x42, 0xfc,
+    0x80, 0x75, 0xd2, 0x21, 0x2b, 0xf9, 0xfd, 0x5b, 0xd3, 0xf7, 0x06, 0x9a, 0xad, 0xc1, 0x6b, 0x39,
+    0x49, 0x7a, 0xb1, 0x59, 0x15, 0xa6, 0xba, 0x85, 0x93, 0x6b, 0x5d, 0x0e, 0xa9, 0xf6, 0x85, 0x1c,
+    0xc6, 0x0c, 0x14, 0xd4, 0xd3, 0xf8, 0x83, 0xd0, 0xab, 0x94, 0x42, 0x06, 0x95, 0xc7, 0x6d, 0xeb,
+    0x2c, 0x75, 0x52,
+];
+
+/// [Appendix A. Test Vectors, 128-bit block](https://datatracker.ietf.org/doc/html/rfc9058.html#appendix-A)
+pub const KUZNYECHIK_MGM_TAG: [u8; 16] = [
+    0xcf, 0x5d, 0x65, 0x6f, 0x40, 0xc3, 0x4f, 0x5c, 0x46, 0xe8, 0xbb, 0x0e, 0x29, 0xfc, 0xdb, 0x4c,
+];
+
+/// [Section 10.1](https://datatracker.ietf.org/doc/html/rfc6986.html#section-10.1), message bytes in memory order (the RFC prints them reversed)
+pub const STREEBOG_M1: [u8; 63] = [
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35,
+    0x36, 0x37, 0x38, 0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x30, 0x31,
+    0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37,
+    0x38, 0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x30, 0x31, 0x32,
+];
+
+/// [Section 10.1](https://datatracker.ietf.org/doc/html/rfc6986.html#section-10.1), hash code bytes in memory order
+pub const STREEBOG512_M1: [u8; 64] = [
+    0x1b, 0x54, 0xd0, 0x1a, 0x4a, 0xf5, 0xb9, 0xd5, 0xcc, 0x3d, 0x86, 0xd6, 0x8d, 0x28, 0x54, 0x62,
+    0xb1, 0x9a, 0xbc, 0x24, 0x75, 0x22, 0x2f, 0x35, 0xc0, 0x85, 0x12, 0x2b, 0xe4, 0xba, 0x1f, 0xfa,
+    0x00, 0xad, 0x30, 0xf8, 0x76, 0x7b, 0x3a, 0x82, 0x38, 0x4c, 0x65, 0x74, 0xf0, 0x24, 0xc3, 0x11,
+    0xe2, 0xa4, 0x81, 0x33, 0x2b, 0x08, 0xef, 0x7f, 0x41, 0x79, 0x78, 0x91, 0xc1, 0x64, 0x6f, 0x48,
+];
+
+/// [Section 10.1](https://datatracker.ietf.org/doc/html/rfc6986.html#section-10.1), hash code bytes in memory order
+pub const STREEBOG256_M1: [u8; 32] = [
+    0x9d, 0x15, 0x1e, 0xef, 0xd8, 0x59, 0x0b, 0x89, 0xda, 0xa6, 0xba, 0x6c, 0xb7, 0x4a, 0xf9, 0x27,
+    0x5d, 0xd0, 0x51, 0x02, 0x6b, 0xb1, 0x49, 0xa4, 0x52, 0xfd, 0x84, 0xe5, 0xe5, 0x7b, 0x55, 0x00,
+];
+
+/// [Section 10.2](https://datatracker.ietf.org/doc/html/rfc6986.html#section-10.2), message bytes in memory order (the RFC prints them reversed)
+pub const STREEBOG_M2: [u8; 72] = [
+    0xd1, 0xe5, 0x20, 0xe2, 0xe5, 0xf2, 0xf0, 0xe8, 0x2c, 0x20, 0xd1, 0xf2, 0xf0, 0xe8, 0xe1, 0xee,
+    0xe6, 0xe8, 0x20, 0xe2, 0xed, 0xf3, 0xf6, 0xe8, 0x2c, 0x20, 0xe2, 0xe5, 0xfe, 0xf2, 0xfa, 0x20,
+    0xf1, 0x20, 0xec, 0xee, 0xf0, 0xff, 0x20, 0xf1, 0xf2, 0xf0, 0xe5, 0xeb, 0xe0, 0xec, 0xe8, 0x20,
+    0xed, 0xe0, 0x20, 0xf5, 0xf0, 0xe0, 0xe1, 0xf0, 0xfb, 0xff, 0x20, 0xef, 0xeb, 0xfa, 0xea, 0xfb,
+    0x20, 0xc8, 0xe3, 0xee, 0xf0, 0xe5, 0xe2, 0xfb,
+];
+
+/// [Section 10.2](https://datatracker.ietf.org/doc/html/rfc6986.html#section-10.2), hash code bytes in memory order
+pub const STREEBOG512_M2: [u8; 64] = [
+    0x1e, 0x88, 0xe6, 0x22, 0x26, 0xbf, 0xca, 0x6f, 0x99, 0x94, 0xf1, 0xf2, 0xd5, 0x15, 0x69, 0xe0,
+    0xda, 0xf8, 0x47, 0x5a, 0x3b, 0x0f, 0xe6, 0x1a, 0x53, 0x00, 0xee, 0xe4, 0x6d, 0x96, 0x13, 0x76,
+    0x03, 0x5f, 0xe8, 0x35, 0x49, 0xad, 0xa2, 0xb8, 0x62, 0x0f, 0xcd, 0x7c, 0x49, 0x6c, 0xe5, 0xb3,
+    0x3f, 0x0c, 0xb9, 0xdd, 0xdc, 0x2b, 0x64, 0x60, 0x14, 0x3b, 0x03, 0xda, 0xba, 0xc9, 0xfb, 0x28,
+];
+
+/// [Section 10.2](https://datatracker.ietf.org/doc/html/rfc6986.html#section-10.2), hash code bytes in memory order
+pub const STREEBOG256_M2: [u8; 32] = [
+    0x9d, 0xd2, 0xfe, 0x4e, 0x90, 0x40, 0x9e, 0x5d, 0xa8, 0x7f, 0x53, 0x97, 0x6d, 0x74, 0x05, 0xb0,
+    0xc0, 0xca, 0xc6, 0x28, 0xfc, 0x66, 0x9a, 0x74, 0x1d, 0x50, 0x06, 0x3c, 0x55, 0x7e, 0x8f, 0x50,
+];
+
+/// [Section 7.1](https://datatracker.ietf.org/doc/html/rfc7836.html#section-7.1), R 50.1.113-2016
+pub const HMAC_KEY: [u8; 32] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+];
+
+/// [Section 7.1](https://datatracker.ietf.org/doc/html/rfc7836.html#section-7.1), R 50.1.113-2016
+pub const HMAC_DATA: [u8; 16] = [
+    0x01, 0x26, 0xbd, 0xb8, 0x78, 0x00, 0xaf, 0x21, 0x43, 0x41, 0x45, 0x65, 0x63, 0x78, 0x01, 0x00,
+];
+
+/// [Section 7.1](https://datatracker.ietf.org/doc/html/rfc7836.html#section-7.1), R 50.1.113-2016
+pub const HMAC_STREEBOG256: [u8; 32] = [
+    0xa1, 0xaa, 0x5f, 0x7d, 0xe4, 0x02, 0xd7, 0xb3, 0xd3, 0x23, 0xf2, 0x99, 0x1c, 0x8d, 0x45, 0x34,
+    0x01, 0x31, 0x37, 0x01, 0x0a, 0x83, 0x75, 0x4f, 0xd0, 0xaf, 0x6d, 0x7c, 0xd4, 0x92, 0x2e, 0xd9,
+];
+
+/// [Section 7.1](https://datatracker.ietf.org/doc/html/rfc7836.html#section-7.1), R 50.1.113-2016
+pub const HMAC_STREEBOG512: [u8; 64] = [
+    0xa5, 0x9b, 0xab, 0x22, 0xec, 0xae, 0x19, 0xc6, 0x5f, 0xbd, 0xe6, 0xe5, 0xf4, 0xe9, 0xf5, 0xd8,
+    0x54, 0x9d, 0x31, 0xf0, 0x37, 0xf9, 0xdf, 0x9b, 0x90, 0x55, 0x00, 0xe1, 0x71, 0x92, 0x3a, 0x77,
+    0x3d, 0x5f, 0x15, 0x30, 0xf2, 0xed, 0x7e, 0x96, 0x4c, 0xb2, 0xee, 0xdc, 0x29, 0xe9, 0xad, 0x2f,
+    0x3a, 0xfe, 0x93, 0xb2, 0x81, 0x4f, 0x79, 0xf5, 0x00, 0x0f, 0xfc, 0x03, 0x66, 0xc2, 0x51, 0xe6,
+];
+
+/// [Section 7.2](https://datatracker.ietf.org/doc/html/rfc7836.html#section-7.2), R 50.1.113-2016
+pub const KDF_LABEL: [u8; 4] = [
+    0x26, 0xbd, 0xb8, 0x78,
+];
+
+/// [Section 7.2](https://datatracker.ietf.org/doc/html/rfc7836.html#section-7.2), R 50.1.113-2016
+pub const KDF_SEED: [u8; 8] = [
+    0xaf, 0x21, 0x43, 0x41, 0x45, 0x65, 0x63, 0x78,
+];
+
+/// [Section 7.2](https://datatracker.ietf.org/doc/html/rfc7836.html#section-7.2), R 50.1.113-2016
+pub const KDF_256: [u8; 32] = [
+    0xa1, 0xaa, 0x5f, 0x7d, 0xe4, 0x02, 0xd7, 0xb3, 0xd3, 0x23, 0xf2, 0x99, 0x1c, 0x8d, 0x45, 0x34,
+    0x01, 0x31, 0x37, 0x01, 0x0a, 0x83, 0x75, 0x4f, 0xd0, 0xaf, 0x6d, 0x7c, 0xd4, 0x92, 0x2e, 0xd9,
+];
+
+
+/// Deterministic xorshift64 generator for randomized tests
+pub struct XorShift64(pub u64);
+
+impl XorShift64 {
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns approximately normal value with standard deviation `sigma`,
+    /// as a sum of 12 uniform values
+    pub fn gaussian(&mut self, sigma: i32) -> i32 {
+        let sum: i64 = (0..12).map(|_| (self.next_u64() >> 48) as i64).sum();
+        (((sum - 6 * (1 << 16)) * sigma as i64) >> 16) as i32
+    }
+}
+
+/// Amplitude of a BPSK symbol at the receiver
+pub const BPSK_AMPLITUDE: i32 = 32;
+
+/// Returns LLR of `bit` sent with BPSK over AWGN channel with noise `sigma`
+pub fn channel_llr(bit: bool, rng: &mut XorShift64, sigma: i32) -> i8 {
+    let symbol = if bit { -BPSK_AMPLITUDE } else { BPSK_AMPLITUDE };
+    (symbol + rng.gaussian(sigma)).clamp(-127, 127) as i8
+}
+
+/// Second section key `K^2 = ACPKM(K^1)` of *Magma* CTR-ACPKM with `K^1 = KUZNYECHIK_KEY`, [Appendix A.2](https://datatracker.ietf.org/doc/html/rfc8645.html#appendix-A.2)
+pub const ACPKM_MAGMA_K2: [u8; 32] = [
+    0x86, 0x3e, 0xa0, 0x17, 0x84, 0x2c, 0x3d, 0x37, 0x2b, 0x18, 0xa8, 0x5a, 0x28, 0xe2, 0x31, 0x7d,
+    0x74, 0xbe, 0xfc, 0x10, 0x77, 0x20, 0xde, 0x0c, 0x9e, 0x8a, 0xb9, 0x74, 0xab, 0xd0, 0x0c, 0xa0,
+];
+
+/// Plaintext of CTR-ACPKM and OMAC-ACPKM examples, [Appendix A](https://datatracker.ietf.org/doc/html/rfc8645.html#appendix-A)
+pub const ACPKM_PLAINTEXT: [u8; 112] = [
+    0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x00, 0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88,
+    0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xee, 0xff, 0x0a,
+    0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xee, 0xff, 0x0a, 0x00,
+    0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xee, 0xff, 0x0a, 0x00, 0x11,
+    0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xee, 0xff, 0x0a, 0x00, 0x11, 0x22,
+    0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xee, 0xff, 0x0a, 0x00, 0x11, 0x22, 0x33,
+    0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xee, 0xff, 0x0a, 0x00, 0x11, 0x22, 0x33, 0x44,
+];
+
+/// *Kuznyechik* CTR-ACPKM ciphertext of `ACPKM_PLAINTEXT`, `N = 256` bits, `IV = KUZNYECHIK_CTR_IV`, [Appendix A.1](https://datatracker.ietf.org/doc/html/rfc8645.html#appendix-A.1)
+pub const KUZNYECHIK_CTR_ACPKM_CIPHERTEXT: [u8; 112] = [
+    0xf1, 0x95, 0xd8, 0xbe, 0xc1, 0x0e, 0xd1, 0xdb, 0xd5, 0x7b, 0x5f, 0xa2, 0x40, 0xbd, 0xa1, 0xb8,
+    0x85, 0xee, 0xe7, 0x33, 0xf6, 0xa1, 0x3e, 0x5d, 0xf3, 0x3c, 0xe4, 0xb3, 0x3c, 0x45, 0xde, 0xe4,
+    0x4b, 0xce, 0xeb, 0x8f, 0x64, 0x6f, 0x4c, 0x55, 0x00, 0x17, 0x06, 0x27, 0x5e, 0x85, 0xe8, 0x00,
+    0x58, 0x7c, 0x4d, 0xf5, 0x68, 0xd0, 0x94, 0x39, 0x3e, 0x48, 0x34, 0xaf, 0xd0, 0x80, 0x50, 0x46,
+    0xcf, 0x30, 0xf5, 0x76, 0x86, 0xae, 0xec, 0xe1, 0x1c, 0xfc, 0x6c, 0x31, 0x6b, 0x8a, 0x89, 0x6e,
+    0xdf, 0xfd, 0x07, 0xec, 0x81, 0x36, 0x36, 0x46, 0x0c, 0x4f, 0x3b, 0x74, 0x34, 0x23, 0x16, 0x3e,
+    0x64, 0x09, 0xa9, 0xc2, 0x82, 0xfa, 0xc8, 0xd4, 0x69, 0xd2, 0x21, 0xe7, 0xfb, 0xd6, 0xde, 0x5d,
+];
+
+/// *Magma* CTR-ACPKM ciphertext of `ACPKM_PLAINTEXT`, `N = 128` bits, `IV = CTR_IV`, [Appendix A.2](https://datatracker.ietf.org/doc/html/rfc8645.html#appendix-A.2)
+pub const MAGMA_CTR_ACPKM_CIPHERTEXT: [u8; 112] = [
+    0x2a, 0xb8, 0x1d, 0xee, 0xeb, 0x1e, 0x4c, 0xab, 0x68, 0xe1, 0x04, 0xc4, 0xbd, 0x6b, 0x94, 0xea,
+    0xc7, 0x2c, 0x67, 0xaf, 0x6c, 0x2e, 0x5b, 0x6b, 0x0e, 0xaf, 0xb6, 0x17, 0x70, 0xf1, 0xb3, 0x2e,
+    0xa1, 0xae, 0x71, 0x14, 0x9e, 0xed, 0x13, 0x82, 0xab, 0xd4, 0x67, 0x18, 0x06, 0x72, 0xec, 0x6f,
+    0x84, 0xa2, 0xf1, 0x5b, 0x3f, 0xca, 0x72, 0xc1, 0x55, 0x59, 0xfb, 0xd3, 0x8c, 0x4c, 0x7c, 0x5d,
+    0xa9, 0x0d, 0x5a, 0xdb, 0xbd, 0x3d, 0x22, 0xf9, 0x2b, 0x22, 0x83, 0xb6, 0x86, 0x43, 0x9f, 0xb4,
+    0x79, 0x6f, 0xa8, 0xa3, 0xfe, 0x3b, 0x7e, 0xc3, 0x9e, 0x48, 0xc8, 0x96, 0xf9, 0x0e, 0x10, 0x97,
+    0xa9, 0x35, 0x10, 0x73, 0xa3, 0x7a, 0x74, 0x2c, 0x05, 0x69, 0xc8, 0xd4, 0x45, 0xfa, 0xea, 0xc5,
+];
+
+/// *Kuznyechik* OMAC-ACPKM of the first 24 bytes of `ACPKM_PLAINTEXT`, `N = 256`, `T* = 768` bits, [Appendix A.1](https://datatracker.ietf.org/doc/html/rfc8645.html#appendix-A.1)
+pub const KUZNYECHIK_OMAC_ACPKM_SHORT: u128 = 0xb5367f47b62b995eeb2a648c5843145e;
+/// *Kuznyechik* OMAC-ACPKM of the first 80 bytes of `ACPKM_PLAINTEXT`, `N = 256`, `T* = 768` bits, [Appendix A.1](https://datatracker.ietf.org/doc/html/rfc8645.html#appendix-A.1)
+pub const KUZNYECHIK_OMAC_ACPKM_LONG: u128 = 0xfbb8dcee45bea67c35f58c5700898e5d;
+
+/// *Magma* OMAC-ACPKM of the first 12 bytes of `ACPKM_PLAINTEXT`, `N = 128`, `T* = 640` bits, [Appendix A.2](https://datatracker.ietf.org/doc/html/rfc8645.html#appendix-A.2)
+pub const MAGMA_OMAC_ACPKM_SHORT: u64 = 0xa0540e3730acbcf3;
+/// *Magma* OMAC-ACPKM of the first 40 bytes of `ACPKM_PLAINTEXT`, `N = 128`, `T* = 640` bits, [Appendix A.2](https://datatracker.ietf.org/doc/html/rfc8645.html#appendix-A.2)
+pub const MAGMA_OMAC_ACPKM_LONG: u64 = 0x34008dad5496bb8e;
+
+/// Export key `K_Exp_MAC` of the KExp15 example, R 1323565.1.017-2018
+pub const KEXP15_K_EXP_MAC: [u8; 32] = [
+    0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+];
+
+/// Export key `K_Exp_ENC` of the KExp15 example, R 1323565.1.017-2018
+pub const KEXP15_K_EXP_ENC: [u8; 32] = [
+    0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d, 0x2e, 0x2f,
+    0x38, 0x39, 0x3a, 0x3b, 0x3c, 0x3d, 0x3e, 0x3f, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37,
+];
+
+/// Initializing value of the KExp15 example, R 1323565.1.017-2018
+pub const KEXP15_IV: u32 = 0x67bed654;
+
+/// *Magma* KExp15 of `KUZNYECHIK_KEY` under `KEXP15_K_EXP_MAC`, `KEXP15_K_EXP_ENC` and `KEXP15_IV`, R 1323565.1.017-2018
+pub const KEXP15_MAGMA_EXPORT: [u8; 40] = [
+    0xcf, 0xd5, 0xa1, 0x2d, 0x5b, 0x81, 0xb6, 0xe1, 0xe9, 0x9c, 0x91, 0x6d, 0x07, 0x90, 0x0c, 0x6a,
+    0xc1, 0x27, 0x03, 0xfb, 0x3a, 0xbd, 0xed, 0x55, 0x56, 0x7b, 0xf3, 0x74, 0x2c, 0x89, 0x9c, 0x75,
+    0x5d, 0xaf, 0xe7, 0xb4, 0x2e, 0x3a, 0x8b, 0xd9,
+];
+
+/// Key `K_1` of the GOST R 34.11-94 example for the message of 32 bytes,
+/// as bytes on the wire: words of the key are little-endian
+pub const GOST28147_TEST_KEY: [u8; 32] = [
+    0x54, 0x6d, 0x20, 0x33, 0x68, 0x65, 0x6c, 0x32, 0x69, 0x73, 0x65, 0x20, 0x73, 0x73, 0x6e, 0x62,
+    0x20, 0x61, 0x67, 0x79, 0x69, 0x67, 0x74, 0x74, 0x73, 0x65, 0x68, 0x65, 0x20, 0x2c, 0x3d, 0x73,
+];
 
+/// GOST 28147-89 with id-Gost28147-89-TestParamSet: the zero block encrypted
+/// under `GOST28147_TEST_KEY`, `s_1 = 42ABBCCE 32BC0B1B` of the GOST R 34.11-94 example
+/// as bytes on the wire
+pub const GOST28147_TEST_CIPHERTEXT: [u8; 8] = [0x1b, 0x0b, 0xbc, 0x32, 0xce, 0xbc, 0xab, 0x42];